@@ -1,4 +1,309 @@
-use std::path::PathBuf;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use clap::{Parser, Subcommand, ValueEnum};
+use polars::prelude::*;
+
+use crate::experiment::slide::Slide;
+use crate::experiment::trial::{PolarizationState, PowerMeterLabel, Trial};
+use crate::measurement::power_meter::newportmodel835powermeter::NewportModel835PowerMeterMeasurementBackground;
+use crate::measurement::power_meter::thorlabspm100a::ThorLabsPM100A_S120VC_PowerMeterMeasurement;
+
+// The concrete trial a batch run constructs: f64 math with Newport background
+// readings on both sensors, matching the Python binding's `Trial` instantiation.
+type BatchTrial = Trial<
+    NewportModel835PowerMeterMeasurementBackground,
+    NewportModel835PowerMeterMeasurementBackground,
+    f64,
+>;
+
+/// Batch-processes one or more trial folders, reading each trial's
+/// `computation_parameters.csv` and data CSV and running the requested
+/// analysis across a set of incident angles.
+#[derive(Parser)]
+#[command(name = "data_analysis", about = "Batch-process optical efficiency trials")]
+pub struct BatchCli {
+    /// Glob-style pattern selecting the trial folders to process, e.g.
+    /// `Trials/GR13-*`.
+    #[arg(long, default_value = "*")]
+    pub trials: String,
+
+    /// Incident angles (in degrees) to evaluate. Each must be present in the
+    /// trial data.
+    #[arg(long, value_delimiter = ',')]
+    pub incident_angles: Vec<f64>,
+
+    /// Output format for the per-trial summary.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Csv)]
+    pub format: OutputFormat,
+
+    /// Zero-based column in the data CSV holding the reflected-sensor power.
+    #[arg(long, default_value_t = 0)]
+    pub reflected_power_column: u8,
+    /// Zero-based column in the data CSV holding the transmitted-sensor power.
+    #[arg(long, default_value_t = 1)]
+    pub transmitted_power_column: u8,
+    /// Zero-based column in the data CSV holding the incident angle.
+    #[arg(long, default_value_t = 2)]
+    pub incident_angle_column: u8,
+    /// Zero-based column in the data CSV holding the mirror angle.
+    #[arg(long, default_value_t = 3)]
+    pub mirror_angle_column: u8,
+    /// Power-meter label on the reflected channel (`A`/`SensorA` or `C`/`SensorC`).
+    #[arg(long, default_value = "A")]
+    pub reflected_sensor: String,
+    /// Power-meter label on the transmitted channel.
+    #[arg(long, default_value = "C")]
+    pub transmitted_sensor: String,
+    /// Beam polarization (`H`/`Horizontal` or `V`/`Vertical`).
+    #[arg(long, default_value = "H")]
+    pub polarization: String,
+    /// Acquisition wavelength (nm), used to pick a spectrally-calibrated meter's
+    /// uncertainty.
+    #[arg(long, default_value_t = 633.0)]
+    pub wavelength: f64,
+    /// Reflected-sensor background power (watts).
+    #[arg(long, default_value_t = 0.0)]
+    pub reflected_background: f64,
+    /// Transmitted-sensor background power (watts).
+    #[arg(long, default_value_t = 0.0)]
+    pub transmitted_background: f64,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+/// The analyses a batch run can perform.
+#[derive(Subcommand)]
+pub enum Command {
+    /// Efficiency vs incident angle.
+    Efficiency,
+    /// Efficiency vs mirror angle, grouped by incident angle.
+    EfficiencyMirror,
+    /// Incident power vs mirror angle, grouped by incident angle.
+    Power,
+    /// Efficiency with its propagated uncertainty.
+    Uncertainty,
+    /// Efficiency vs incident angle with a Monte-Carlo error bar per point.
+    ErrorMc,
+    /// Efficiency vs incident angle with an analytical (first-order) error bar
+    /// per point, for comparison against the Monte-Carlo path.
+    ErrorAnalytical,
+}
+
+/// Selects the serialization of the summary table.
+#[derive(Copy, Clone, ValueEnum)]
+pub enum OutputFormat {
+    Csv,
+    Json,
+}
+
+/// Errors surfaced while batch-processing trial folders.
+#[derive(Debug)]
+pub enum BatchError {
+    Glob(String),
+    Io(PolarsError),
+    Parse(String),
+}
+
+impl From<PolarsError> for BatchError {
+    fn from(error: PolarsError) -> Self {
+        BatchError::Io(error)
+    }
+}
+
+impl BatchCli {
+    /// Expands the trial glob and processes every matching folder, printing one
+    /// summary row per trial in the requested format.
+    pub fn run(&self) -> Result<(), BatchError> {
+        let folders = glob::glob(&self.trials)
+            .map_err(|e| BatchError::Glob(e.to_string()))?
+            .filter_map(Result::ok)
+            .filter(|path| path.is_dir())
+            .collect::<Vec<_>>();
+
+        self.validate_calibration()?;
+
+        let incident_angles: HashSet<f64> = self.incident_angles.iter().copied().collect();
+        let mut summaries = Vec::with_capacity(folders.len());
+        for folder in folders {
+            summaries.push(self.process_folder(&folder, &incident_angles)?);
+        }
+
+        match self.format {
+            OutputFormat::Csv => print_csv(&summaries),
+            OutputFormat::Json => print_json(&summaries),
+        }
+        Ok(())
+    }
+
+    // Rejects a run whose acquisition wavelength falls outside the ThorLabs
+    // S120VC calibrated band when either channel is read by that meter, so an
+    // out-of-band sweep fails fast rather than reporting a silently-widened
+    // uncertainty. Uses the meter's own `checked_uncertainty` error path.
+    fn validate_calibration(&self) -> Result<(), BatchError> {
+        for sensor in [&self.reflected_sensor, &self.transmitted_sensor] {
+            if matches!(parse_label(sensor)?, PowerMeterLabel::SensorC) {
+                ThorLabsPM100A_S120VC_PowerMeterMeasurement::new(0.0, self.wavelength)
+                    .checked_uncertainty()
+                    .map_err(|e| {
+                        BatchError::Parse(format!(
+                            "acquisition wavelength {} nm is outside the ThorLabs S120VC calibrated band: {e:?}",
+                            self.wavelength
+                        ))
+                    })?;
+            }
+        }
+        Ok(())
+    }
+
+    // Loads a single trial folder's parameter and data CSVs, assembles the
+    // concrete `Trial`, runs the selected computation over the requested
+    // incident angles and returns a one-line summary of the result.
+    fn process_folder(
+        &self,
+        folder: &Path,
+        incident_angles: &HashSet<f64>,
+    ) -> Result<TrialSummary, BatchError> {
+        let parameters = CsvReader::from_path(folder.join("computation_parameters.csv"))?
+            .has_header(true)
+            .finish()?;
+        let data = CsvReader::from_path(folder.join("data.csv"))?
+            .has_header(true)
+            .finish()?;
+        let data_rows = data.height();
+
+        let label = folder.to_string_lossy().into_owned();
+        let trial = self.build_trial(label.clone(), &parameters, &data)?;
+
+        // Dispatch to the matching compute method and summarise the output
+        // shape so a mis-specified angle set is obvious in the batch output.
+        let result_rows = match self.command {
+            Command::Efficiency => trial
+                .compute_efficiency_vs_incident_angle(incident_angles.clone())
+                .nrows(),
+            Command::EfficiencyMirror => trial
+                .compute_efficiency_vs_mirror_angle(incident_angles.clone())
+                .values()
+                .map(|array| array.nrows())
+                .sum(),
+            Command::Power => trial
+                .compute_power_vs_mirror_angle(incident_angles.clone())
+                .values()
+                .map(|array| array.nrows())
+                .sum(),
+            Command::Uncertainty => trial
+                .compute_efficiency_vs_incident_angle_error_mc(incident_angles.clone())
+                .nrows(),
+            Command::ErrorMc => trial
+                .compute_efficiency_vs_incident_angle_mc(incident_angles.clone())
+                .nrows(),
+            Command::ErrorAnalytical => trial
+                .compute_efficiency_vs_incident_angle_analytical(incident_angles.clone())
+                .nrows(),
+        };
+
+        Ok(TrialSummary {
+            label,
+            command: self.command.name(),
+            data_rows,
+            result_rows,
+        })
+    }
+
+    // Assembles the concrete `Trial` from the two loaded frames and the column
+    // layout / sensor labels / polarization supplied on the command line.
+    fn build_trial(
+        &self,
+        label: String,
+        parameters: &DataFrame,
+        data: &DataFrame,
+    ) -> Result<BatchTrial, BatchError> {
+        let full_data_set = data.to_ndarray::<Float64Type>()?;
+        let optical_coefficients = parameters.to_ndarray::<Float64Type>()?;
+
+        Ok(Trial {
+            label,
+            full_data_set,
+            transmitted_sensor_background: NewportModel835PowerMeterMeasurementBackground::new(
+                self.transmitted_background,
+            ),
+            reflected_sensor_background: NewportModel835PowerMeterMeasurementBackground::new(
+                self.reflected_background,
+            ),
+            transmitted_power_column_number: self.transmitted_power_column,
+            transmitted_power_meter_label: parse_label(&self.transmitted_sensor)?,
+            reflected_power_column_number: self.reflected_power_column,
+            reflected_power_meter_label: parse_label(&self.reflected_sensor)?,
+            incident_angle_column: self.incident_angle_column,
+            mirror_angle_column: self.mirror_angle_column,
+            polarization_state: parse_polarization(&self.polarization)?,
+            slide: Slide::new(optical_coefficients),
+            wavelength: self.wavelength,
+            mc_seed: None,
+        })
+    }
+}
+
+// Parses a power-meter label, accepting both the short (`A`/`C`) and long forms.
+fn parse_label(label: &str) -> Result<PowerMeterLabel, BatchError> {
+    match label {
+        "A" | "SensorA" => Ok(PowerMeterLabel::SensorA),
+        "C" | "SensorC" => Ok(PowerMeterLabel::SensorC),
+        other => Err(BatchError::Parse(format!("unknown power meter label: {other}"))),
+    }
+}
+
+// Parses a polarization state from its short or long form.
+fn parse_polarization(state: &str) -> Result<PolarizationState, BatchError> {
+    match state {
+        "H" | "Horizontal" => Ok(PolarizationState::Horizontal),
+        "V" | "Vertical" => Ok(PolarizationState::Vertical),
+        other => Err(BatchError::Parse(format!("unknown polarization: {other}"))),
+    }
+}
+
+impl Command {
+    fn name(&self) -> &'static str {
+        match self {
+            Command::Efficiency => "efficiency",
+            Command::EfficiencyMirror => "efficiency-mirror",
+            Command::Power => "power",
+            Command::Uncertainty => "uncertainty",
+            Command::ErrorMc => "error-mc",
+            Command::ErrorAnalytical => "error-analytical",
+        }
+    }
+}
+
+/// A single trial's batch-processing result, rendered into the summary table.
+struct TrialSummary {
+    label: String,
+    command: &'static str,
+    data_rows: usize,
+    result_rows: usize,
+}
+
+fn print_csv(summaries: &[TrialSummary]) {
+    println!("trial,command,data_rows,result_rows");
+    for s in summaries {
+        println!("{},{},{},{}", s.label, s.command, s.data_rows, s.result_rows);
+    }
+}
+
+fn print_json(summaries: &[TrialSummary]) {
+    let rows = summaries
+        .iter()
+        .map(|s| {
+            format!(
+                "{{\"trial\":\"{}\",\"command\":\"{}\",\"data_rows\":{},\"result_rows\":{}}}",
+                s.label, s.command, s.data_rows, s.result_rows
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    println!("[{rows}]");
+}
 
 // def main(
 //     trial: Tuple[Tuple[Path, str]],
@@ -13,18 +318,99 @@ use std::path::PathBuf;
 //     theory: bool,
 //     title: str
 // ):
-struct Cli {
+/// Mirrors the `plotdata.py` signature: the trials to plot together, the
+/// grating angle of each, and the plotting switches.
+#[derive(Parser)]
+pub struct Cli {
+    /// A trial to plot, given as `PATH:LABEL`. Repeat for each trial.
+    #[arg(long = "trial", value_parser = parse_trial_spec)]
     trials: Vec<(PathBuf, String)>,
+    /// The grating angle (degrees) of each trial, in the same order, as a
+    /// comma-separated list.
+    #[arg(long, value_delimiter = ',')]
     grating_angles: Vec<f64>,
+    #[arg(long)]
     grating_plot: bool,
+    #[arg(long)]
     mirror_plot: bool,
+    #[arg(long)]
     power_plot: bool,
+    #[arg(long, default_value_t = 1.0)]
     scale: f64,
+    #[arg(long)]
     show_figure: bool,
+    #[arg(long)]
     save_figure: bool,
+    #[arg(long)]
     reuse_figure: bool,
+    #[arg(long)]
     theory: bool,
+    #[arg(long, default_value_t = String::new())]
     title: String,
 }
 
+impl Cli {
+    /// Validates that exactly one grating angle was supplied per trial.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.grating_angles.len() != self.trials.len() {
+            return Err(format!(
+                "expected one grating angle per trial, but got {} angles for {} trials",
+                self.grating_angles.len(),
+                self.trials.len()
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Parses a `PATH:LABEL` token into its components, trimming surrounding and
+/// trailing whitespace from both halves. Copy-pasted entries frequently carry
+/// stray spaces or trailing newlines; trimming them keeps a harmless path like
+/// ` Trials/foo : bar ` from silently resolving to a directory that does not
+/// exist.
+fn parse_trial_spec(spec: &str) -> Result<(PathBuf, String), String> {
+    let (path, label) = spec
+        .split_once(':')
+        .ok_or_else(|| format!("trial spec `{spec}` must be of the form PATH:LABEL"))?;
+    let path = path.trim();
+    let label = label.trim();
+    if path.is_empty() || label.is_empty() {
+        return Err(format!("trial spec `{spec}` has an empty path or label"));
+    }
+    Ok((PathBuf::from(path), label.to_owned()))
+}
+
 // Make From implementation for python class that stores the CLI args that are produced in plotdata.py
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_trial_spec_splits_path_and_label() {
+        let (path, label) = parse_trial_spec("Trials/foo:bar").unwrap();
+        assert_eq!(PathBuf::from("Trials/foo"), path);
+        assert_eq!("bar", label);
+    }
+
+    #[test]
+    fn parse_trial_spec_trims_surrounding_whitespace() {
+        // Stray spaces and a trailing newline from a copy-pasted entry must not
+        // leak into the resolved path or label.
+        let (path, label) = parse_trial_spec(" Trials/foo : bar \n").unwrap();
+        assert_eq!(PathBuf::from("Trials/foo"), path);
+        assert_eq!("bar", label);
+    }
+
+    #[test]
+    fn parse_trial_spec_rejects_missing_separator() {
+        assert!(parse_trial_spec("Trials/foo").is_err());
+    }
+
+    #[test]
+    fn parse_trial_spec_rejects_empty_halves() {
+        assert!(parse_trial_spec(":bar").is_err());
+        assert!(parse_trial_spec("Trials/foo:").is_err());
+        assert!(parse_trial_spec("  :  ").is_err());
+    }
+}
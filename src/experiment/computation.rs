@@ -2,46 +2,621 @@ use std::collections::HashSet;
 
 use polars::export::ahash::HashMap;
 
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rand_distr::{Distribution, Normal};
+
+use crate::measurement::power_meter::newportmodel835powermeter::NewportModel835PowerMeterMeasurement;
+use crate::measurement::power_meter::thorlabspm100a::ThorLabsPM100A_S120VC_PowerMeterMeasurement;
+use crate::measurement::power_measurement::Uncertain;
 use crate::{
-    experiment::trial::Trial,
-    measurement::power_measurement::traits::{ArithmeticOps, Background},
+    experiment::trial::{PowerMeterLabel, Trial},
+    measurement::power_measurement::traits::{
+        AbsoluteUncertainty, ArithmeticOps, Background, Efficiency, EfficiencyAnalytical,
+        EfficiencyMonteCarlo, FromF64, IncidentPower, Measurement,
+    },
 };
 
-// Implement some functions for a trial which uses f64 number types
-impl<BackgroundT, BackgroundR> Trial<BackgroundT, BackgroundR, f64>
+/// A single raw power reading (or background reading) tagged with the meter
+/// that produced it, so Monte-Carlo and analytical propagation can draw its
+/// uncertainty from the real per-meter model. It serves as both a
+/// [`Measurement`] and a [`Background`] so it flows through the existing
+/// propagation traits unchanged.
+enum MeterReading {
+    Newport(NewportModel835PowerMeterMeasurement),
+    ThorLabs(ThorLabsPM100A_S120VC_PowerMeterMeasurement),
+}
+
+impl MeterReading {
+    // Builds the reading for `label`, carrying the trial wavelength so the
+    // spectrally-calibrated ThorLabs sensor picks the correct uncertainty.
+    fn new(label: &PowerMeterLabel, value: f64, wavelength: f64) -> MeterReading {
+        match label {
+            PowerMeterLabel::SensorA => {
+                MeterReading::Newport(NewportModel835PowerMeterMeasurement::new(value))
+            }
+            PowerMeterLabel::SensorC => MeterReading::ThorLabs(
+                ThorLabsPM100A_S120VC_PowerMeterMeasurement::new(value, wavelength),
+            ),
+        }
+    }
+}
+
+impl Measurement<f64> for MeterReading {
+    fn value(&self) -> f64 {
+        match self {
+            MeterReading::Newport(m) => m.value(),
+            MeterReading::ThorLabs(m) => m.value(),
+        }
+    }
+}
+
+impl Background<f64> for MeterReading {
+    fn background(&self) -> f64 {
+        self.value()
+    }
+}
+
+impl AbsoluteUncertainty<f64> for MeterReading {
+    fn uncertainty(&self) -> f64 {
+        match self {
+            MeterReading::Newport(m) => m.uncertainty(),
+            MeterReading::ThorLabs(m) => m.uncertainty(),
+        }
+    }
+
+    fn acquisition_wavelength(&self) -> Option<f64> {
+        match self {
+            MeterReading::Newport(m) => m.acquisition_wavelength(),
+            MeterReading::ThorLabs(m) => m.acquisition_wavelength(),
+        }
+    }
+}
+
+/// Default number of Monte-Carlo draws per data point. Roughly 10^4 keeps the
+/// 16th/84th percentile estimates stable while staying cheap enough to run over
+/// a full angle sweep.
+const DEFAULT_MC_SAMPLES: usize = 10_000;
+
+/// Default RNG seed used when a `Trial` has not been pinned with `with_seed`.
+const DEFAULT_MC_SEED: u64 = 0;
+
+/// A resampled or raw reading, reused both as a `Measurement` and as a
+/// `Background` so it can flow through the existing compute math unchanged.
+struct Reading<V>(V);
+
+impl<V: ArithmeticOps + Copy> Measurement<V> for Reading<V> {
+    fn value(&self) -> V {
+        self.0
+    }
+}
+
+impl<V: ArithmeticOps + Copy> Background<V> for Reading<V> {
+    fn background(&self) -> V {
+        self.0
+    }
+}
+
+// Generic efficiency/power computations over the pipeline's number type, so the
+// same code path serves the default f64 build and the f32 fast mode.
+impl<BackgroundT, BackgroundR, NumberType> Trial<BackgroundT, BackgroundR, NumberType>
 where
-    BackgroundT: Background<f64>,
-    BackgroundR: Background<f64>,
+    NumberType: ArithmeticOps + Copy + FromF64 + PartialEq,
+    BackgroundT: Background<NumberType>,
+    BackgroundR: Background<NumberType>,
 {
     /// Computes the efficiency vs mirror angle for the given incident angles. The provided incident angles must be explicitly present in the data.
     pub fn compute_efficiency_vs_mirror_angle(
-        &mut self,
+        &self,
         incident_angles: HashSet<f64>,
-    ) -> HashMap<f64, ndarray::Array2<f64>> {
-        todo!()
+    ) -> HashMap<f64, ndarray::Array2<NumberType>> {
+        self.grouped_by_incident_angle(incident_angles, |trial, reflected, refl_bg, transmitted, trans_bg| {
+            trial.slide.compute_efficiency(
+                &Reading(reflected),
+                &Reading(refl_bg),
+                &trial.reflected_power_meter_label,
+                &Reading(transmitted),
+                &Reading(trans_bg),
+                &trial.transmitted_power_meter_label,
+                &trial.polarization_state,
+            )
+        })
     }
 
     /// Computes the efficiency vs incident angle for the given incident angles. The provided incident angles must be explicitly present in the data.
     pub fn compute_efficiency_vs_incident_angle(
-        &mut self,
+        &self,
         incident_angles: HashSet<f64>,
-    ) -> ndarray::Array2<f64> {
-        todo!()
+    ) -> ndarray::Array2<NumberType> {
+        let wanted = self.wanted_angles(&incident_angles);
+        let incident_column = self.incident_angle_column as usize;
+        let mirror_column = self.mirror_angle_column as usize;
+
+        let mut rows: Vec<[NumberType; 3]> = Vec::new();
+        for row in self.full_data_set.rows() {
+            if !wanted.iter().any(|angle| *angle == row[incident_column]) {
+                continue;
+            }
+            let efficiency = self.evaluate(&row, |trial, reflected, refl_bg, transmitted, trans_bg| {
+                trial.slide.compute_efficiency(
+                    &Reading(reflected),
+                    &Reading(refl_bg),
+                    &trial.reflected_power_meter_label,
+                    &Reading(transmitted),
+                    &Reading(trans_bg),
+                    &trial.transmitted_power_meter_label,
+                    &trial.polarization_state,
+                )
+            });
+            rows.push([row[incident_column], row[mirror_column], efficiency]);
+        }
+        stack_rows(rows)
+    }
+
+    /// Computes the powers vs mirror angle for the given incident angles. The provided incident angles must be explicitly present in the data.
+    pub fn compute_power_vs_mirror_angle(
+        &self,
+        incident_angles: HashSet<f64>,
+    ) -> HashMap<f64, ndarray::Array2<NumberType>> {
+        self.grouped_by_incident_angle(incident_angles, |trial, reflected, refl_bg, transmitted, trans_bg| {
+            trial.slide.compute_incident_power(
+                &Reading(reflected),
+                &Reading(refl_bg),
+                &trial.reflected_power_meter_label,
+                &Reading(transmitted),
+                &Reading(trans_bg),
+                &trial.transmitted_power_meter_label,
+                &trial.polarization_state,
+            )
+        })
+    }
+
+    // The requested incident angles lifted into the pipeline's number type so
+    // they can be matched against the (typed) incident-angle column.
+    fn wanted_angles(&self, incident_angles: &HashSet<f64>) -> Vec<NumberType> {
+        incident_angles
+            .iter()
+            .map(|angle| NumberType::from_f64(*angle))
+            .collect()
     }
 
+    // Evaluates `compute` on a single data row's background-subtracted readings.
+    fn evaluate<F>(&self, row: &ndarray::ArrayView1<NumberType>, compute: F) -> NumberType
+    where
+        F: Fn(&Self, NumberType, NumberType, NumberType, NumberType) -> NumberType,
+    {
+        let reflected = row[self.reflected_power_column_number as usize];
+        let transmitted = row[self.transmitted_power_column_number as usize];
+        compute(
+            self,
+            reflected,
+            self.reflected_sensor_background.background(),
+            transmitted,
+            self.transmitted_sensor_background.background(),
+        )
+    }
+
+    // Groups the result of `compute` per mirror angle, keyed by incident angle.
+    fn grouped_by_incident_angle<F>(
+        &self,
+        incident_angles: HashSet<f64>,
+        compute: F,
+    ) -> HashMap<f64, ndarray::Array2<NumberType>>
+    where
+        F: Fn(&Self, NumberType, NumberType, NumberType, NumberType) -> NumberType,
+    {
+        let incident_column = self.incident_angle_column as usize;
+        let mirror_column = self.mirror_angle_column as usize;
+
+        let mut grouped: HashMap<f64, Vec<[NumberType; 2]>> = HashMap::default();
+        for angle in incident_angles {
+            let wanted = NumberType::from_f64(angle);
+            let mut rows: Vec<[NumberType; 2]> = Vec::new();
+            for row in self.full_data_set.rows() {
+                if row[incident_column] != wanted {
+                    continue;
+                }
+                let result = self.evaluate(&row, &compute);
+                rows.push([row[mirror_column], result]);
+            }
+            grouped.insert(angle, rows);
+        }
+        grouped
+            .into_iter()
+            .map(|(angle, rows)| (angle, stack_pairs(rows)))
+            .collect()
+    }
+}
+
+// Implement the Monte-Carlo error propagation for a trial which uses f64 number
+// types (the sampler relies on f64 normal distributions).
+impl<BackgroundT, BackgroundR> Trial<BackgroundT, BackgroundR, f64>
+where
+    BackgroundT: Background<f64>,
+    BackgroundR: Background<f64>,
+{
     /// Computes the one-sided error in the efficiencies vs incident angles. The provided incident angles must be explicitly present in the data.
     pub fn compute_efficiency_vs_incident_angle_error(
-        &mut self,
+        &self,
         incident_angles: HashSet<f64>,
     ) -> ndarray::Array2<f64> {
-        todo!()
+        // The analytic one-sided error is superseded by the Monte-Carlo path,
+        // which captures the nonlinear combination faithfully.
+        self.compute_efficiency_vs_incident_angle_error_mc(incident_angles)
     }
 
-    /// Computes the powers vs mirror angle for the given incident angles. The provided incident angles must be explicitly present in the data.
-    pub fn compute_power_vs_mirror_angle(
-        &mut self,
+    /// Propagates measurement uncertainty into the efficiency-vs-incident-angle
+    /// result by Monte-Carlo resampling rather than a single analytic one-sided
+    /// error. Each reading and background is treated as a random variable drawn
+    /// from `Normal(value, uncertainty)` (negative sampled powers are truncated
+    /// to zero), the background is subtracted per draw, and the efficiency is
+    /// recomputed through the existing [`Efficiency`] implementation. The ratio
+    /// in `compute_efficiency` is strongly nonlinear near zero power, so the
+    /// output distribution is skewed; we therefore report the empirical mean
+    /// together with asymmetric 16th/84th-percentile bounds rather than a
+    /// symmetric ±σ.
+    ///
+    /// The returned array has one row per `(incident angle, mirror angle)` pair
+    /// present in the data with columns `[incident_angle, mirror_angle, mean,
+    /// lower_bound, upper_bound]`. Pin the RNG with
+    /// [`Trial::with_seed`](crate::experiment::trial::Trial::with_seed) for
+    /// reproducible bounds.
+    pub fn compute_efficiency_vs_incident_angle_error_mc(
+        &self,
+        incident_angles: HashSet<f64>,
+    ) -> ndarray::Array2<f64> {
+        self.compute_efficiency_vs_incident_angle_error_mc_with_samples(
+            incident_angles,
+            DEFAULT_MC_SAMPLES,
+        )
+    }
+
+    /// Like [`compute_efficiency_vs_incident_angle_error_mc`](Self::compute_efficiency_vs_incident_angle_error_mc)
+    /// but with an explicit sample count for callers that want to trade speed
+    /// against the precision of the percentile estimates.
+    pub fn compute_efficiency_vs_incident_angle_error_mc_with_samples(
+        &self,
+        incident_angles: HashSet<f64>,
+        samples: usize,
+    ) -> ndarray::Array2<f64> {
+        let incident_angle_column = self.incident_angle_column as usize;
+        let mirror_angle_column = self.mirror_angle_column as usize;
+        let reflected_column = self.reflected_power_column_number as usize;
+        let transmitted_column = self.transmitted_power_column_number as usize;
+
+        let reflected_background = self.reflected_sensor_background.background();
+        let transmitted_background = self.transmitted_sensor_background.background();
+
+        let mut rng = ChaCha8Rng::seed_from_u64(self.mc_seed.unwrap_or(DEFAULT_MC_SEED));
+
+        // One output row per data row that matches a requested incident angle.
+        let mut rows: Vec<[f64; 5]> = Vec::new();
+        for row in self.full_data_set.rows() {
+            let incident_angle = row[incident_angle_column];
+            if !incident_angles.contains(&incident_angle) {
+                continue;
+            }
+            let mirror_angle = row[mirror_angle_column];
+            let reflected_power = row[reflected_column];
+            let transmitted_power = row[transmitted_column];
+
+            let mut efficiencies = Vec::with_capacity(samples);
+            for _ in 0..samples {
+                let reflected = draw(
+                    &mut rng,
+                    reflected_power,
+                    self.reading_uncertainty(&self.reflected_power_meter_label, reflected_power),
+                );
+                let reflected_bg = draw(
+                    &mut rng,
+                    reflected_background,
+                    self.reading_uncertainty(&self.reflected_power_meter_label, reflected_background),
+                );
+                let transmitted = draw(
+                    &mut rng,
+                    transmitted_power,
+                    self.reading_uncertainty(&self.transmitted_power_meter_label, transmitted_power),
+                );
+                let transmitted_bg = draw(
+                    &mut rng,
+                    transmitted_background,
+                    self.reading_uncertainty(
+                        &self.transmitted_power_meter_label,
+                        transmitted_background,
+                    ),
+                );
+
+                let efficiency = self.slide.compute_efficiency(
+                    &Reading(reflected),
+                    &Reading(reflected_bg),
+                    &self.reflected_power_meter_label,
+                    &Reading(transmitted),
+                    &Reading(transmitted_bg),
+                    &self.transmitted_power_meter_label,
+                    &self.polarization_state,
+                );
+                if efficiency.is_finite() {
+                    efficiencies.push(efficiency);
+                }
+            }
+
+            let (mean, lower, upper) = summarize(&mut efficiencies);
+            rows.push([incident_angle, mirror_angle, mean, lower, upper]);
+        }
+
+        let mut result = ndarray::Array2::<f64>::zeros((rows.len(), 5));
+        for (i, row) in rows.into_iter().enumerate() {
+            result.row_mut(i).assign(&ndarray::arr1(&row));
+        }
+        result
+    }
+
+    /// Computes the efficiency vs incident angle together with its first-order
+    /// propagated uncertainty in a single pass, by instantiating the generic
+    /// [`Efficiency`] computation with `V = Uncertain<f64>`. Each reading and
+    /// background enters as an [`Uncertain`] whose variance is seeded from the
+    /// meter that produced it, so the uncertainty on the efficiency falls out
+    /// of the same arithmetic that produces the value — no separate error
+    /// formula to keep in sync. The returned array has columns
+    /// `[incident_angle, mirror_angle, efficiency, uncertainty]`.
+    pub fn compute_efficiency_vs_incident_angle_uncertain(
+        &self,
+        incident_angles: HashSet<f64>,
+    ) -> ndarray::Array2<f64> {
+        let slide = self.slide.with_exact_uncertainty();
+        let incident_column = self.incident_angle_column as usize;
+        let mirror_column = self.mirror_angle_column as usize;
+        let reflected_column = self.reflected_power_column_number as usize;
+        let transmitted_column = self.transmitted_power_column_number as usize;
+
+        let reflected_background = self.reflected_sensor_background.background();
+        let transmitted_background = self.transmitted_sensor_background.background();
+
+        let reflected = |power: f64| {
+            Reading(Uncertain::new(
+                power,
+                self.reading_uncertainty(&self.reflected_power_meter_label, power),
+            ))
+        };
+        let transmitted = |power: f64| {
+            Reading(Uncertain::new(
+                power,
+                self.reading_uncertainty(&self.transmitted_power_meter_label, power),
+            ))
+        };
+
+        let mut rows: Vec<[f64; 4]> = Vec::new();
+        for row in self.full_data_set.rows() {
+            let incident_angle = row[incident_column];
+            if !incident_angles.contains(&incident_angle) {
+                continue;
+            }
+            let efficiency = slide.compute_efficiency(
+                &reflected(row[reflected_column]),
+                &reflected(reflected_background),
+                &self.reflected_power_meter_label,
+                &transmitted(row[transmitted_column]),
+                &transmitted(transmitted_background),
+                &self.transmitted_power_meter_label,
+                &self.polarization_state,
+            );
+            rows.push([
+                incident_angle,
+                row[mirror_column],
+                efficiency.value,
+                efficiency.uncertainty(),
+            ]);
+        }
+
+        let mut result = ndarray::Array2::<f64>::zeros((rows.len(), 4));
+        for (i, row) in rows.into_iter().enumerate() {
+            result.row_mut(i).assign(&ndarray::arr1(&row));
+        }
+        result
+    }
+
+    /// Efficiency vs incident angle with a Monte-Carlo error bar per point,
+    /// dispatched through [`EfficiencyMonteCarlo`] so the sampled mean and
+    /// standard deviation land in the returned array's `value`/`error` columns.
+    /// Columns are `[incident_angle, mirror_angle, efficiency, error]`. Pin the
+    /// RNG with [`Trial::with_seed`](crate::experiment::trial::Trial::with_seed).
+    pub fn compute_efficiency_vs_incident_angle_mc(
+        &self,
+        incident_angles: HashSet<f64>,
+    ) -> ndarray::Array2<f64> {
+        let seed = self.mc_seed.unwrap_or(DEFAULT_MC_SEED);
+        self.efficiency_with_error(incident_angles, |slide, reflected, reflected_bg, transmitted, transmitted_bg| {
+            slide.compute_efficiency_mc(
+                reflected,
+                reflected_bg,
+                &self.reflected_power_meter_label,
+                transmitted,
+                transmitted_bg,
+                &self.transmitted_power_meter_label,
+                &self.polarization_state,
+                seed,
+                DEFAULT_MC_SAMPLES,
+            )
+        })
+    }
+
+    /// Efficiency vs incident angle with an analytical (first-order) error bar
+    /// per point, dispatched through [`EfficiencyAnalytical`]. This is the fast,
+    /// correlation-free alternative to the Monte-Carlo path and returns the same
+    /// `[incident_angle, mirror_angle, efficiency, error]` columns, so callers
+    /// can compare the two on one trial.
+    pub fn compute_efficiency_vs_incident_angle_analytical(
+        &self,
+        incident_angles: HashSet<f64>,
+    ) -> ndarray::Array2<f64> {
+        self.efficiency_with_error(incident_angles, |slide, reflected, reflected_bg, transmitted, transmitted_bg| {
+            slide.compute_efficiency_analytical(
+                reflected,
+                reflected_bg,
+                &self.reflected_power_meter_label,
+                transmitted,
+                transmitted_bg,
+                &self.transmitted_power_meter_label,
+                &self.polarization_state,
+            )
+        })
+    }
+
+    // Shared driver for the per-point error computations: builds the four
+    // meter-tagged readings for each matching data row, runs `propagate`, and
+    // stacks `[incident_angle, mirror_angle, value, error]` rows.
+    fn efficiency_with_error<F>(
+        &self,
         incident_angles: HashSet<f64>,
-    ) -> HashMap<f64, ndarray::Array2<f64>> {
-        todo!()
+        propagate: F,
+    ) -> ndarray::Array2<f64>
+    where
+        F: Fn(
+            &crate::experiment::slide::Slide<f64>,
+            &MeterReading,
+            &MeterReading,
+            &MeterReading,
+            &MeterReading,
+        ) -> crate::experiment::trial::ComputationResult<f64>,
+    {
+        let incident_column = self.incident_angle_column as usize;
+        let mirror_column = self.mirror_angle_column as usize;
+        let reflected_column = self.reflected_power_column_number as usize;
+        let transmitted_column = self.transmitted_power_column_number as usize;
+
+        let reflected_background = self.reflected_sensor_background.background();
+        let transmitted_background = self.transmitted_sensor_background.background();
+        let reflected = |value: f64| {
+            MeterReading::new(&self.reflected_power_meter_label, value, self.wavelength)
+        };
+        let transmitted = |value: f64| {
+            MeterReading::new(&self.transmitted_power_meter_label, value, self.wavelength)
+        };
+
+        let mut rows: Vec<[f64; 4]> = Vec::new();
+        for row in self.full_data_set.rows() {
+            let incident_angle = row[incident_column];
+            if !incident_angles.contains(&incident_angle) {
+                continue;
+            }
+            let result = propagate(
+                &self.slide,
+                &reflected(row[reflected_column]),
+                &reflected(reflected_background),
+                &transmitted(row[transmitted_column]),
+                &transmitted(transmitted_background),
+            );
+            rows.push([
+                incident_angle,
+                row[mirror_column],
+                *result.value(),
+                result.associated_error().copied().unwrap_or(0.0),
+            ]);
+        }
+
+        let mut result = ndarray::Array2::<f64>::zeros((rows.len(), 4));
+        for (i, row) in rows.into_iter().enumerate() {
+            result.row_mut(i).assign(&ndarray::arr1(&row));
+        }
+        result
+    }
+
+    /// The absolute uncertainty attached to a raw power reading, derived from
+    /// the meter that actually produced it: the Newport 835 on sensor A and the
+    /// spectrally-calibrated ThorLabs S120VC on sensor C (evaluated at the
+    /// trial's acquisition wavelength).
+    fn reading_uncertainty(&self, label: &PowerMeterLabel, power: f64) -> f64 {
+        match label {
+            PowerMeterLabel::SensorA => {
+                NewportModel835PowerMeterMeasurement::new(power).uncertainty()
+            }
+            PowerMeterLabel::SensorC => {
+                ThorLabsPM100A_S120VC_PowerMeterMeasurement::new(power, self.wavelength).uncertainty()
+            }
+        }
+    }
+}
+
+/// Draws a single power sample from `Normal(value, std)`, truncating negative
+/// powers to zero since a physical power reading cannot be negative. A zero or
+/// non-finite standard deviation degenerates to the mean.
+fn draw(rng: &mut ChaCha8Rng, value: f64, std: f64) -> f64 {
+    match Normal::new(value, std.abs()) {
+        Ok(normal) => normal.sample(rng).max(0.0),
+        Err(_) => value.max(0.0),
+    }
+}
+
+/// Collapses a set of Monte-Carlo efficiency samples into the empirical mean
+/// and the asymmetric 16th/84th-percentile bounds. The slice is sorted in
+/// place. An empty slice (every draw collapsed to a non-finite value) reports
+/// zeros.
+fn summarize(samples: &mut [f64]) -> (f64, f64, f64) {
+    if samples.is_empty() {
+        return (0.0, 0.0, 0.0);
+    }
+    samples.sort_by(|a, b| a.partial_cmp(b).expect("filtered out non-finite samples"));
+    let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+    let lower = percentile(samples, 0.16);
+    let upper = percentile(samples, 0.84);
+    (mean, lower, upper)
+}
+
+/// Nearest-rank percentile over an already-sorted slice.
+fn percentile(sorted: &[f64], quantile: f64) -> f64 {
+    let rank = (quantile * (sorted.len() as f64 - 1.0)).round() as usize;
+    sorted[rank.min(sorted.len() - 1)]
+}
+
+/// Stacks `[a, b, c]` triples into an `N x 3` array.
+fn stack_rows<NumberType: Clone>(rows: Vec<[NumberType; 3]>) -> ndarray::Array2<NumberType> {
+    let flat: Vec<NumberType> = rows.iter().flat_map(|r| r.iter().cloned()).collect();
+    ndarray::Array2::from_shape_vec((rows.len(), 3), flat)
+        .expect("row count matches the flattened length")
+}
+
+/// Stacks `[a, b]` pairs into an `N x 2` array.
+fn stack_pairs<NumberType: Clone>(rows: Vec<[NumberType; 2]>) -> ndarray::Array2<NumberType> {
+    let flat: Vec<NumberType> = rows.iter().flat_map(|r| r.iter().cloned()).collect();
+    ndarray::Array2::from_shape_vec((rows.len(), 2), flat)
+        .expect("row count matches the flattened length")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentile_uses_nearest_rank() {
+        let sorted = [0.0, 1.0, 2.0, 3.0, 4.0];
+        // Nearest-rank over 5 points: 0.16 -> rank 0.64 ~ 1, 0.84 -> rank 3.36 ~ 3.
+        assert_eq!(1.0, percentile(&sorted, 0.16));
+        assert_eq!(3.0, percentile(&sorted, 0.84));
+        // The endpoints map to the first and last samples.
+        assert_eq!(0.0, percentile(&sorted, 0.0));
+        assert_eq!(4.0, percentile(&sorted, 1.0));
+    }
+
+    #[test]
+    fn summarize_reports_mean_and_percentile_bounds() {
+        let mut samples: Vec<f64> = (0..=100).map(|i| i as f64).collect();
+        let (mean, lower, upper) = summarize(&mut samples);
+        assert!((mean - 50.0).abs() < 1e-9);
+        // 101 points: 0.16 -> rank 16, 0.84 -> rank 84.
+        assert_eq!(16.0, lower);
+        assert_eq!(84.0, upper);
+    }
+
+    #[test]
+    fn summarize_sorts_in_place() {
+        let mut samples = [3.0, 1.0, 2.0];
+        summarize(&mut samples);
+        assert_eq!([1.0, 2.0, 3.0], samples);
+    }
+
+    #[test]
+    fn summarize_of_empty_samples_is_zero() {
+        let mut samples: [f64; 0] = [];
+        assert_eq!((0.0, 0.0, 0.0), summarize(&mut samples));
     }
 }
@@ -1,65 +1,179 @@
 use crate::experiment::trial::PowerMeterLabel;
 use crate::measurement::power_measurement::traits::{
-    ArithmeticOps, Background, Efficiency, IncidentPower, Measurement,
+    AbsoluteUncertainty, ArithmeticOps, Background, Efficiency, EfficiencyAnalytical,
+    EfficiencyMonteCarlo, FromF64, IncidentPower, IncidentPowerAnalytical, IncidentPowerMonteCarlo,
+    Measurement,
 };
 
-use crate::experiment::trial::PolarizationState;
+use crate::experiment::trial::{ComputationResult, ComputationType, PolarizationState};
+use crate::measurement::power_measurement::Uncertain;
 
 use ndarray::Array2;
 
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rand_distr::{Distribution, Normal};
+
+/// Default number of Monte-Carlo draws per computation.
+const DEFAULT_MC_SAMPLES: usize = 100_000;
+
+/// A single Monte-Carlo draw, reusable as both a reading and a background so it
+/// flows through the existing compute closures unchanged.
+struct Draw(f64);
+
+impl Measurement<f64> for Draw {
+    fn value(&self) -> f64 {
+        self.0
+    }
+}
+
+impl Background<f64> for Draw {
+    fn background(&self) -> f64 {
+        self.0
+    }
+}
+
+// Draws one non-negative power sample from Normal(value, |std|). A zero or
+// non-finite std degenerates to the mean.
+fn sample(rng: &mut ChaCha8Rng, value: f64, std: f64) -> f64 {
+    match Normal::new(value, std.abs()) {
+        Ok(normal) => normal.sample(rng).max(0.0),
+        Err(_) => value.max(0.0),
+    }
+}
+
+// Sample mean and (N-1) standard deviation of the finite samples collected.
+fn mean_and_std(samples: &[f64]) -> (f64, f64) {
+    let n = samples.len() as f64;
+    let mean = samples.iter().sum::<f64>() / n;
+    let variance = if samples.len() > 1 {
+        samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)
+    } else {
+        0.0
+    };
+    (mean, variance.sqrt())
+}
+
 pub struct Slide<NumberType: ArithmeticOps> {
     optical_coefficients: Array2<NumberType>,
 }
 
-impl Slide<f64> {
-    fn get_reflectivity_column_index(&self, polarization: &PolarizationState) -> usize {
-        match polarization {
-            PolarizationState::Horizontal => 0,
-            PolarizationState::Vertical => 2,
+impl<NumberType: ArithmeticOps> Slide<NumberType> {
+    /// Constructs a slide from its table of optical coefficients (one row per
+    /// sensor, one column per s/p reflectivity/transmissivity coefficient).
+    pub fn new(optical_coefficients: Array2<NumberType>) -> Slide<NumberType> {
+        Slide {
+            optical_coefficients,
         }
     }
+}
 
+impl Slide<f64> {
+    /// Lifts the slide's exact optical coefficients into [`Uncertain`] numbers
+    /// with zero variance, so the efficiency computation can be instantiated
+    /// with `V = Uncertain<f64>` and propagate the readings' uncertainty through
+    /// the same generic code path that produces the value.
+    pub(crate) fn with_exact_uncertainty(&self) -> Slide<Uncertain<f64>> {
+        Slide::new(self.optical_coefficients.mapv(Uncertain::exact))
+    }
+}
+
+// Column layout of `optical_coefficients`: one column per (s/p) x
+// (reflectivity/transmissivity) coefficient, one row per sensor.
+const COLUMN_S_REFLECTIVITY: usize = 0;
+const COLUMN_S_TRANSMISSIVITY: usize = 1;
+const COLUMN_P_REFLECTIVITY: usize = 2;
+const COLUMN_P_TRANSMISSIVITY: usize = 3;
+
+impl<T: ArithmeticOps + Copy + FromF64 + PartialEq> Slide<T> {
     fn get_sensor_label_row_index(&self, sensor_label: &PowerMeterLabel) -> usize {
         match sensor_label {
             PowerMeterLabel::SensorA => 0,
             PowerMeterLabel::SensorC => 1,
         }
     }
+
+    fn coefficient(&self, sensor_label: &PowerMeterLabel, column: usize) -> T {
+        *self
+            .optical_coefficients
+            .get((self.get_sensor_label_row_index(sensor_label), column))
+            .expect("Invalid optical coefficients in computation_parameters.csv")
+    }
+
+    /// The polarization-weighted reflectivity seen by `sensor_label`, combining
+    /// the s- and p-component coefficients by the beam's polarization split.
+    fn effective_reflectivity(
+        &self,
+        sensor_label: &PowerMeterLabel,
+        polarization: &PolarizationState,
+    ) -> T {
+        T::from_f64(polarization.s_fraction()) * self.coefficient(sensor_label, COLUMN_S_REFLECTIVITY)
+            + T::from_f64(polarization.p_fraction())
+                * self.coefficient(sensor_label, COLUMN_P_REFLECTIVITY)
+    }
+
+    /// The polarization-weighted transmissivity seen by `sensor_label`.
+    fn effective_transmissivity(
+        &self,
+        sensor_label: &PowerMeterLabel,
+        polarization: &PolarizationState,
+    ) -> T {
+        T::from_f64(polarization.s_fraction())
+            * self.coefficient(sensor_label, COLUMN_S_TRANSMISSIVITY)
+            + T::from_f64(polarization.p_fraction())
+                * self.coefficient(sensor_label, COLUMN_P_TRANSMISSIVITY)
+    }
 }
 
 // Define IncidentPower for a slide. This takes the reflected and transmitted powers and the polarization and computes a power result
-impl IncidentPower<f64> for Slide<f64> {
+impl<V: ArithmeticOps + Copy + FromF64 + PartialEq> IncidentPower<V> for Slide<V> {
     fn compute_incident_power<R, Br, T, Tr>(
         &self,
         reflected_power: &R,
         reflected_power_background: &Br,
         reflected_power_meter_label: &PowerMeterLabel,
-        _transmitted_power: &T,
-        _transmitted_power_background: &Tr,
-        _transmitted_power_meter_label: &PowerMeterLabel,
+        transmitted_power: &T,
+        transmitted_power_background: &Tr,
+        transmitted_power_meter_label: &PowerMeterLabel,
         polarization: &PolarizationState,
-    ) -> f64
+    ) -> V
     where
-        R: Measurement<f64>,
-        Br: Background<f64>,
-        Tr: Background<f64>,
-        T: Measurement<f64>,
+        R: Measurement<V>,
+        Br: Background<V>,
+        Tr: Background<V>,
+        T: Measurement<V>,
     {
-        let reflectivity_coefficient = *self
-            .optical_coefficients
-            .get((
-                self.get_sensor_label_row_index(reflected_power_meter_label),
-                self.get_reflectivity_column_index(polarization),
-            ))
-            .expect("Invalid optical coefficients in computation_parameters.csv");
+        // Background-subtracted powers at each sensor.
+        let reflected = reflected_power.value() - reflected_power_background.background();
+        let transmitted =
+            transmitted_power.value() - transmitted_power_background.background();
+
+        // Combine the s- and p-component coefficients according to the beam's
+        // polarization split (`P_s·coeff_s + P_p·coeff_p`), then recover the
+        // incident power. The detected reflected and transmitted powers sum to
+        // `P_incident·(R + T)`, so the incident power is the background-subtracted
+        // total divided by the summed coefficients rather than the mean of the
+        // two per-sensor ratios — the latter over-weights whichever channel has
+        // the smaller coefficient. When either effective coefficient is zero,
+        // its channel is dropped and the incident power is estimated from the
+        // other sensor alone.
+        let reflectivity =
+            self.effective_reflectivity(reflected_power_meter_label, polarization);
+        let transmissivity =
+            self.effective_transmissivity(transmitted_power_meter_label, polarization);
 
-        (reflected_power.value() - reflected_power_background.background())
-            / reflectivity_coefficient
+        if transmissivity == V::from_f64(0.0) {
+            reflected / reflectivity
+        } else if reflectivity == V::from_f64(0.0) {
+            transmitted / transmissivity
+        } else {
+            (reflected + transmitted) / (reflectivity + transmissivity)
+        }
     }
 }
 
 // Define IncidentPower for a slide. This takes the reflected and transmitted powers and the polarization and computes an efficiency
-impl Efficiency<f64> for Slide<f64> {
+impl<V: ArithmeticOps + Copy + FromF64 + PartialEq> Efficiency<V> for Slide<V> {
     fn compute_efficiency<R, Br, T, Tr>(
         &self,
         reflected_power: &R,
@@ -69,20 +183,15 @@ impl Efficiency<f64> for Slide<f64> {
         transmitted_power_background: &Tr,
         _transmitted_power_meter_label: &PowerMeterLabel,
         polarization: &PolarizationState,
-    ) -> f64
+    ) -> V
     where
-        R: Measurement<f64>,
-        Br: Background<f64>,
-        Tr: Background<f64>,
-        T: Measurement<f64>,
+        R: Measurement<V>,
+        Br: Background<V>,
+        Tr: Background<V>,
+        T: Measurement<V>,
     {
-        let reflectivity_coefficient = *self
-            .optical_coefficients
-            .get((
-                self.get_sensor_label_row_index(reflected_power_meter_label),
-                self.get_reflectivity_column_index(polarization),
-            ))
-            .expect("Invalid optical coefficients in computation_parameters.csv");
+        let reflectivity_coefficient =
+            self.effective_reflectivity(reflected_power_meter_label, polarization);
 
         // (power_b-trial.sensor_b_background)/(((power_a - trial.sensor_a_background)/reflectivity) - (power_a - trial.sensor_a_background))
         (transmitted_power.value() - transmitted_power_background.background())
@@ -92,7 +201,346 @@ impl Efficiency<f64> for Slide<f64> {
     }
 }
 
-#[cfg(tests)]
+// Monte-Carlo error propagation for the incident-power computation.
+impl IncidentPowerMonteCarlo for Slide<f64> {
+    #[allow(clippy::too_many_arguments)]
+    fn compute_incident_power_mc<R, Br, T, Tr>(
+        &self,
+        reflected_power: &R,
+        reflected_power_background: &Br,
+        reflected_power_meter_label: &PowerMeterLabel,
+        transmitted_power: &T,
+        transmitted_power_background: &Tr,
+        transmitted_power_meter_label: &PowerMeterLabel,
+        polarization: &PolarizationState,
+        seed: u64,
+        samples: usize,
+    ) -> ComputationResult<f64>
+    where
+        R: Measurement<f64> + AbsoluteUncertainty<f64>,
+        Br: Background<f64> + AbsoluteUncertainty<f64>,
+        Tr: Background<f64> + AbsoluteUncertainty<f64>,
+        T: Measurement<f64> + AbsoluteUncertainty<f64>,
+    {
+        let draws = resample(
+            reflected_power,
+            reflected_power_background,
+            transmitted_power,
+            transmitted_power_background,
+            seed,
+            samples,
+            |reflected, reflected_bg, transmitted, transmitted_bg| {
+                self.compute_incident_power(
+                    reflected,
+                    reflected_bg,
+                    reflected_power_meter_label,
+                    transmitted,
+                    transmitted_bg,
+                    transmitted_power_meter_label,
+                    polarization,
+                )
+            },
+        );
+        let (mean, std) = mean_and_std(&draws);
+        ComputationResult::new(mean, Some(std), ComputationType::PowerVsMirrorAngle)
+    }
+}
+
+// Monte-Carlo error propagation for the efficiency computation.
+impl EfficiencyMonteCarlo for Slide<f64> {
+    #[allow(clippy::too_many_arguments)]
+    fn compute_efficiency_mc<R, Br, T, Tr>(
+        &self,
+        reflected_power: &R,
+        reflected_power_background: &Br,
+        reflected_power_meter_label: &PowerMeterLabel,
+        transmitted_power: &T,
+        transmitted_power_background: &Tr,
+        transmitted_power_meter_label: &PowerMeterLabel,
+        polarization: &PolarizationState,
+        seed: u64,
+        samples: usize,
+    ) -> ComputationResult<f64>
+    where
+        R: Measurement<f64> + AbsoluteUncertainty<f64>,
+        Br: Background<f64> + AbsoluteUncertainty<f64>,
+        Tr: Background<f64> + AbsoluteUncertainty<f64>,
+        T: Measurement<f64> + AbsoluteUncertainty<f64>,
+    {
+        let draws = resample(
+            reflected_power,
+            reflected_power_background,
+            transmitted_power,
+            transmitted_power_background,
+            seed,
+            samples,
+            |reflected, reflected_bg, transmitted, transmitted_bg| {
+                self.compute_efficiency(
+                    reflected,
+                    reflected_bg,
+                    reflected_power_meter_label,
+                    transmitted,
+                    transmitted_bg,
+                    transmitted_power_meter_label,
+                    polarization,
+                )
+            },
+        );
+        let (mean, std) = mean_and_std(&draws);
+        ComputationResult::new(mean, Some(std), ComputationType::EfficiencyVsIncidentAngle)
+    }
+}
+
+// Draws `samples` (defaulting to `DEFAULT_MC_SAMPLES` when zero) independent
+// resamples of the four inputs and evaluates `compute` on each. Non-finite
+// results — e.g. when the efficiency denominator collapses toward zero — are
+// discarded so they cannot poison the accumulated statistics.
+fn resample<R, Br, T, Tr, F>(
+    reflected_power: &R,
+    reflected_power_background: &Br,
+    transmitted_power: &T,
+    transmitted_power_background: &Tr,
+    seed: u64,
+    samples: usize,
+    compute: F,
+) -> Vec<f64>
+where
+    R: Measurement<f64> + AbsoluteUncertainty<f64>,
+    Br: Background<f64> + AbsoluteUncertainty<f64>,
+    Tr: Background<f64> + AbsoluteUncertainty<f64>,
+    T: Measurement<f64> + AbsoluteUncertainty<f64>,
+    F: Fn(&Draw, &Draw, &Draw, &Draw) -> f64,
+{
+    let samples = if samples == 0 {
+        DEFAULT_MC_SAMPLES
+    } else {
+        samples
+    };
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let mut results = Vec::with_capacity(samples);
+    for _ in 0..samples {
+        let reflected = sample(&mut rng, reflected_power.value(), reflected_power.uncertainty());
+        let reflected_bg = sample(
+            &mut rng,
+            reflected_power_background.background(),
+            reflected_power_background.uncertainty(),
+        );
+        let transmitted = sample(
+            &mut rng,
+            transmitted_power.value(),
+            transmitted_power.uncertainty(),
+        );
+        let transmitted_bg = sample(
+            &mut rng,
+            transmitted_power_background.background(),
+            transmitted_power_background.uncertainty(),
+        );
+        let result = compute(
+            &Draw(reflected),
+            &Draw(reflected_bg),
+            &Draw(transmitted),
+            &Draw(transmitted_bg),
+        );
+        if result.is_finite() {
+            results.push(result);
+        }
+    }
+    results
+}
+
+// Analytical (first-order) error propagation for the incident-power computation.
+impl IncidentPowerAnalytical for Slide<f64> {
+    #[allow(clippy::too_many_arguments)]
+    fn compute_incident_power_analytical<R, Br, T, Tr>(
+        &self,
+        reflected_power: &R,
+        reflected_power_background: &Br,
+        reflected_power_meter_label: &PowerMeterLabel,
+        transmitted_power: &T,
+        transmitted_power_background: &Tr,
+        transmitted_power_meter_label: &PowerMeterLabel,
+        polarization: &PolarizationState,
+    ) -> ComputationResult<f64>
+    where
+        R: Measurement<f64> + AbsoluteUncertainty<f64>,
+        Br: Background<f64> + AbsoluteUncertainty<f64>,
+        Tr: Background<f64> + AbsoluteUncertainty<f64>,
+        T: Measurement<f64> + AbsoluteUncertainty<f64>,
+    {
+        let (value, error) = propagate_first_order(
+            reflected_power,
+            reflected_power_background,
+            transmitted_power,
+            transmitted_power_background,
+            |reflected, reflected_bg, transmitted, transmitted_bg| {
+                self.compute_incident_power(
+                    reflected,
+                    reflected_bg,
+                    reflected_power_meter_label,
+                    transmitted,
+                    transmitted_bg,
+                    transmitted_power_meter_label,
+                    polarization,
+                )
+            },
+        );
+        ComputationResult::new(value, Some(error), ComputationType::PowerVsMirrorAngle)
+    }
+}
+
+// Analytical (first-order) error propagation for the efficiency computation.
+impl EfficiencyAnalytical for Slide<f64> {
+    #[allow(clippy::too_many_arguments)]
+    fn compute_efficiency_analytical<R, Br, T, Tr>(
+        &self,
+        reflected_power: &R,
+        reflected_power_background: &Br,
+        reflected_power_meter_label: &PowerMeterLabel,
+        transmitted_power: &T,
+        transmitted_power_background: &Tr,
+        transmitted_power_meter_label: &PowerMeterLabel,
+        polarization: &PolarizationState,
+    ) -> ComputationResult<f64>
+    where
+        R: Measurement<f64> + AbsoluteUncertainty<f64>,
+        Br: Background<f64> + AbsoluteUncertainty<f64>,
+        Tr: Background<f64> + AbsoluteUncertainty<f64>,
+        T: Measurement<f64> + AbsoluteUncertainty<f64>,
+    {
+        let (value, error) = propagate_first_order(
+            reflected_power,
+            reflected_power_background,
+            transmitted_power,
+            transmitted_power_background,
+            |reflected, reflected_bg, transmitted, transmitted_bg| {
+                self.compute_efficiency(
+                    reflected,
+                    reflected_bg,
+                    reflected_power_meter_label,
+                    transmitted,
+                    transmitted_bg,
+                    transmitted_power_meter_label,
+                    polarization,
+                )
+            },
+        );
+        ComputationResult::new(value, Some(error), ComputationType::EfficiencyVsIncidentAngle)
+    }
+}
+
+// Evaluates `compute` at the nominal inputs and linearly propagates their
+// uncertainties via `σ_f = sqrt(Σ (∂f/∂x_i)² · σ_i²)`, approximating each
+// partial with a central finite difference. The step is scaled to the input's
+// magnitude (`1e-6·|x|`) with a small absolute floor for inputs near zero.
+fn propagate_first_order<R, Br, T, Tr, F>(
+    reflected_power: &R,
+    reflected_power_background: &Br,
+    transmitted_power: &T,
+    transmitted_power_background: &Tr,
+    compute: F,
+) -> (f64, f64)
+where
+    R: Measurement<f64> + AbsoluteUncertainty<f64>,
+    Br: Background<f64> + AbsoluteUncertainty<f64>,
+    Tr: Background<f64> + AbsoluteUncertainty<f64>,
+    T: Measurement<f64> + AbsoluteUncertainty<f64>,
+    F: Fn(&Draw, &Draw, &Draw, &Draw) -> f64,
+{
+    // Nominal values and their 1σ uncertainties, in a fixed input order.
+    let inputs = [
+        (reflected_power.value(), reflected_power.uncertainty()),
+        (
+            reflected_power_background.background(),
+            reflected_power_background.uncertainty(),
+        ),
+        (transmitted_power.value(), transmitted_power.uncertainty()),
+        (
+            transmitted_power_background.background(),
+            transmitted_power_background.uncertainty(),
+        ),
+    ];
+
+    let at = |v: &[f64; 4]| compute(&Draw(v[0]), &Draw(v[1]), &Draw(v[2]), &Draw(v[3]));
+    let nominal: [f64; 4] = [inputs[0].0, inputs[1].0, inputs[2].0, inputs[3].0];
+    let value = at(&nominal);
+
+    let mut variance = 0.0;
+    for (i, (_, sigma)) in inputs.iter().enumerate() {
+        let h = (1e-6 * nominal[i].abs()).max(1e-12);
+        let mut plus = nominal;
+        let mut minus = nominal;
+        plus[i] += h;
+        minus[i] -= h;
+        let partial = (at(&plus) - at(&minus)) / (2.0 * h);
+        variance += (partial * sigma).powi(2);
+    }
+
+    (value, variance.sqrt())
+}
+
+#[cfg(test)]
 mod tests {
     use super::*;
+
+    // A fixed reading carrying an explicit uncertainty, for driving the
+    // propagation helpers directly.
+    struct FixedReading {
+        value: f64,
+        uncertainty: f64,
+    }
+
+    impl Measurement<f64> for FixedReading {
+        fn value(&self) -> f64 {
+            self.value
+        }
+    }
+
+    impl Background<f64> for FixedReading {
+        fn background(&self) -> f64 {
+            self.value
+        }
+    }
+
+    impl AbsoluteUncertainty<f64> for FixedReading {
+        fn uncertainty(&self) -> f64 {
+            self.uncertainty
+        }
+    }
+
+    #[test]
+    fn propagate_first_order_matches_closed_form_for_a_ratio() {
+        // f = a / b with independent a, b. The closed-form relative variance is
+        // (σ_a/a)² + (σ_b/b)², so σ_f = |a/b|·sqrt of that sum. Feed b through
+        // the "transmitted" slot and a through "reflected", with zero-valued,
+        // zero-uncertainty backgrounds, and a compute closure of reflected /
+        // transmitted.
+        let a = FixedReading { value: 4.0, uncertainty: 0.2 };
+        let b = FixedReading { value: 2.0, uncertainty: 0.1 };
+        let zero = FixedReading { value: 0.0, uncertainty: 0.0 };
+
+        let (value, error) = propagate_first_order(&a, &zero, &b, &zero, |ra, ra_bg, tb, tb_bg| {
+            (ra.value() - ra_bg.value()) / (tb.value() - tb_bg.value())
+        });
+
+        let expected_value = 2.0;
+        let expected_error =
+            expected_value * ((0.2 / 4.0f64).powi(2) + (0.1 / 2.0f64).powi(2)).sqrt();
+        assert!((value - expected_value).abs() < 1e-9);
+        assert!((error - expected_error).abs() < 1e-6);
+    }
+
+    #[test]
+    fn propagate_first_order_is_exact_for_a_sum() {
+        // f = a + b: variances add, so σ_f = sqrt(σ_a² + σ_b²).
+        let a = FixedReading { value: 3.0, uncertainty: 0.3 };
+        let b = FixedReading { value: 5.0, uncertainty: 0.4 };
+        let zero = FixedReading { value: 0.0, uncertainty: 0.0 };
+
+        let (value, error) = propagate_first_order(&a, &zero, &b, &zero, |ra, ra_bg, tb, tb_bg| {
+            (ra.value() - ra_bg.value()) + (tb.value() - tb_bg.value())
+        });
+
+        assert!((value - 8.0).abs() < 1e-9);
+        assert!((error - (0.3f64.powi(2) + 0.4f64.powi(2)).sqrt()).abs() < 1e-6);
+    }
 }
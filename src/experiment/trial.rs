@@ -9,24 +9,65 @@ where
     BackgroundR: Background<NumberType>,
     NumberType: ArithmeticOps,
 {
-    label: String,
-    full_data_set: Array2<NumberType>,
-    transmitted_sensor_background: BackgroundT,
-    reflected_sensor_background: BackgroundR,
-    transmitted_power_column_number: u8,
-    transmitted_power_meter_label: PowerMeterLabel,
-    reflected_power_column_number: u8,
-    reflected_power_meter_label: PowerMeterLabel,
-    incident_angle_column: u8,
-    mirror_angle_column: u8,
-    polarization_state: PolarizationState,
-    slide: Slide<NumberType>,
-}
-
-/// An enumeration over the allowed polarization states for light in this experiment.
+    pub(crate) label: String,
+    pub(crate) full_data_set: Array2<NumberType>,
+    pub(crate) transmitted_sensor_background: BackgroundT,
+    pub(crate) reflected_sensor_background: BackgroundR,
+    pub(crate) transmitted_power_column_number: u8,
+    pub(crate) transmitted_power_meter_label: PowerMeterLabel,
+    pub(crate) reflected_power_column_number: u8,
+    pub(crate) reflected_power_meter_label: PowerMeterLabel,
+    pub(crate) incident_angle_column: u8,
+    pub(crate) mirror_angle_column: u8,
+    pub(crate) polarization_state: PolarizationState,
+    pub(crate) slide: Slide<NumberType>,
+    /// Wavelength (nm) the readings were acquired at, used to pick the correct
+    /// uncertainty model for spectrally-calibrated meters (e.g. the ThorLabs
+    /// S120VC).
+    pub(crate) wavelength: f64,
+    /// Seed for the Monte-Carlo uncertainty propagator. When `None` the
+    /// sampler falls back to a fixed default so that runs stay reproducible.
+    pub(crate) mc_seed: Option<u64>,
+}
+
+impl<BackgroundT, BackgroundR, NumberType> Trial<BackgroundT, BackgroundR, NumberType>
+where
+    BackgroundT: Background<NumberType>,
+    BackgroundR: Background<NumberType>,
+    NumberType: ArithmeticOps,
+{
+    /// Fixes the seed used by the Monte-Carlo error propagation, making the
+    /// resampled confidence bounds reproducible across runs.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.mc_seed = Some(seed);
+        self
+    }
+}
+
+/// The polarization of the incident beam. Pure `Horizontal`/`Vertical` states
+/// are fully s- or p-polarized respectively; `Mixed` describes a partially
+/// polarized or elliptical beam by the fraction of its power carried in the
+/// s-component (Stokes-style), with the remainder in the p-component.
 pub enum PolarizationState {
     Horizontal,
     Vertical,
+    Mixed { s_fraction: f64 },
+}
+
+impl PolarizationState {
+    /// The fraction of the beam power in the s- (horizontal) component.
+    pub fn s_fraction(&self) -> f64 {
+        match self {
+            PolarizationState::Horizontal => 1.0,
+            PolarizationState::Vertical => 0.0,
+            PolarizationState::Mixed { s_fraction } => *s_fraction,
+        }
+    }
+
+    /// The fraction of the beam power in the p- (vertical) component.
+    pub fn p_fraction(&self) -> f64 {
+        1.0 - self.s_fraction()
+    }
 }
 /// An enumeration of the two possible power meter labels in the experiment
 pub enum PowerMeterLabel {
@@ -52,6 +93,32 @@ pub struct ComputationResult<T> {
     computation_type: ComputationType,
 }
 
+impl<T> ComputationResult<T> {
+    /// Builds a computation result, optionally carrying the propagated error in
+    /// the dependent variable.
+    pub fn new(
+        value: T,
+        associated_error: Option<T>,
+        computation_type: ComputationType,
+    ) -> ComputationResult<T> {
+        ComputationResult {
+            value,
+            associated_error,
+            computation_type,
+        }
+    }
+
+    /// The computed value.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// The propagated error in the value, if one was computed.
+    pub fn associated_error(&self) -> Option<&T> {
+        self.associated_error.as_ref()
+    }
+}
+
 /// Represents the different possible types of computations available in a trial.
 pub enum ComputationType {
     EfficiencyVsMirrorAngle,
@@ -1,12 +1,239 @@
-use pyo3::{pymodule, types::PyModule, PyResult, Python};
+use std::collections::HashSet;
+use std::path::PathBuf;
 
-mod cli;
+use ndarray::Array2;
+use numpy::{IntoPyArray, PyArray2};
+use polars::prelude::*;
+use pyo3::exceptions::PyIOError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyModule};
+
+use crate::experiment::slide::Slide;
+use crate::experiment::trial::{PolarizationState, PowerMeterLabel, Trial};
+use crate::measurement::power_meter::newportmodel835powermeter::NewportModel835PowerMeterMeasurementBackground;
+use crate::measurement::power_measurement::traits::FromF64;
+
+pub mod cli;
 mod experiment;
 mod measurement;
 
+/// The number type the analysis pipeline is built on. Enabling the `f32`
+/// feature selects single precision, which roughly halves the memory and
+/// speeds up the `Array2` arithmetic for large mirror/incident angle sweeps at
+/// the cost of precision — the same trade made deliberately elsewhere for
+/// compute-bound math. Leave it off for the default double-precision build.
+#[cfg(feature = "f32")]
+pub type Number = f32;
+#[cfg(not(feature = "f32"))]
+pub type Number = f64;
+
+// The concrete `Trial` the Python bindings drive: the crate's selected
+// `Number` precision with Newport background readings on both sensors. The
+// default `f64` build additionally exposes the Monte-Carlo / first-order
+// propagation methods, which are inherently double precision; the `f32` fast
+// mode keeps only the value-only sweeps.
+type PyTrialInner = Trial<
+    NewportModel835PowerMeterMeasurementBackground,
+    NewportModel835PowerMeterMeasurementBackground,
+    Number,
+>;
+
+/// A trial loaded from a folder on disk, exposing the efficiency/power
+/// computations to Python as numpy arrays.
+#[pyclass(name = "Trial")]
+struct PyTrial {
+    inner: PyTrialInner,
+}
+
+#[pymethods]
+impl PyTrial {
+    /// Loads a trial from `folder`, which must contain a
+    /// `computation_parameters.csv` (the slide's optical coefficients) and a
+    /// `data.csv` (the angle/power sweep). The column indices, power-meter
+    /// labels and polarization mirror the fields of the Rust `Trial`.
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        folder: PathBuf,
+        label: String,
+        transmitted_power_column_number: u8,
+        transmitted_sensor: &str,
+        reflected_power_column_number: u8,
+        reflected_sensor: &str,
+        incident_angle_column: u8,
+        mirror_angle_column: u8,
+        polarization: &str,
+        wavelength: f64,
+        transmitted_background: f64,
+        reflected_background: f64,
+    ) -> PyResult<PyTrial> {
+        let full_data_set = read_ndarray(folder.join("data.csv"))?;
+        let optical_coefficients = read_ndarray(folder.join("computation_parameters.csv"))?;
+
+        let inner = Trial {
+            label,
+            full_data_set,
+            transmitted_sensor_background: NewportModel835PowerMeterMeasurementBackground::new(
+                transmitted_background,
+            ),
+            reflected_sensor_background: NewportModel835PowerMeterMeasurementBackground::new(
+                reflected_background,
+            ),
+            transmitted_power_column_number,
+            transmitted_power_meter_label: parse_label(transmitted_sensor)?,
+            reflected_power_column_number,
+            reflected_power_meter_label: parse_label(reflected_sensor)?,
+            incident_angle_column,
+            mirror_angle_column,
+            polarization_state: parse_polarization(polarization)?,
+            slide: Slide::new(optical_coefficients),
+            wavelength,
+            mc_seed: None,
+        };
+        Ok(PyTrial { inner })
+    }
+
+    /// Efficiency vs incident angle as a 2-D numpy array.
+    fn compute_efficiency_vs_incident_angle<'py>(
+        &self,
+        py: Python<'py>,
+        incident_angles: Vec<f64>,
+    ) -> &'py PyArray2<Number> {
+        self.inner
+            .compute_efficiency_vs_incident_angle(into_set(incident_angles))
+            .into_pyarray(py)
+    }
+
+    /// Efficiency vs mirror angle as a dict keyed by incident angle.
+    fn compute_efficiency_vs_mirror_angle<'py>(
+        &self,
+        py: Python<'py>,
+        incident_angles: Vec<f64>,
+    ) -> &'py PyDict {
+        arrays_by_angle(
+            py,
+            self.inner
+                .compute_efficiency_vs_mirror_angle(into_set(incident_angles)),
+        )
+    }
+
+    /// Monte-Carlo propagated efficiency error vs incident angle. Double
+    /// precision only, so it is absent from the `f32` fast-mode build.
+    #[cfg(not(feature = "f32"))]
+    fn compute_efficiency_vs_incident_angle_error_mc<'py>(
+        &self,
+        py: Python<'py>,
+        incident_angles: Vec<f64>,
+    ) -> &'py PyArray2<f64> {
+        self.inner
+            .compute_efficiency_vs_incident_angle_error_mc(into_set(incident_angles))
+            .into_pyarray(py)
+    }
+
+    /// Efficiency vs incident angle with its first-order propagated
+    /// uncertainty, as columns `[incident_angle, mirror_angle, efficiency,
+    /// uncertainty]`. Double precision only, as above.
+    #[cfg(not(feature = "f32"))]
+    fn compute_efficiency_vs_incident_angle_uncertain<'py>(
+        &self,
+        py: Python<'py>,
+        incident_angles: Vec<f64>,
+    ) -> &'py PyArray2<f64> {
+        self.inner
+            .compute_efficiency_vs_incident_angle_uncertain(into_set(incident_angles))
+            .into_pyarray(py)
+    }
+
+    /// Efficiency vs incident angle with a Monte-Carlo error bar per point, as
+    /// columns `[incident_angle, mirror_angle, efficiency, error]`. Double
+    /// precision only.
+    #[cfg(not(feature = "f32"))]
+    fn compute_efficiency_vs_incident_angle_mc<'py>(
+        &self,
+        py: Python<'py>,
+        incident_angles: Vec<f64>,
+    ) -> &'py PyArray2<f64> {
+        self.inner
+            .compute_efficiency_vs_incident_angle_mc(into_set(incident_angles))
+            .into_pyarray(py)
+    }
+
+    /// Efficiency vs incident angle with an analytical (first-order) error bar
+    /// per point, for comparison against the Monte-Carlo path on the same
+    /// trial. Columns `[incident_angle, mirror_angle, efficiency, error]`.
+    /// Double precision only.
+    #[cfg(not(feature = "f32"))]
+    fn compute_efficiency_vs_incident_angle_analytical<'py>(
+        &self,
+        py: Python<'py>,
+        incident_angles: Vec<f64>,
+    ) -> &'py PyArray2<f64> {
+        self.inner
+            .compute_efficiency_vs_incident_angle_analytical(into_set(incident_angles))
+            .into_pyarray(py)
+    }
+
+    /// Powers vs mirror angle as a dict keyed by incident angle.
+    fn compute_power_vs_mirror_angle<'py>(
+        &self,
+        py: Python<'py>,
+        incident_angles: Vec<f64>,
+    ) -> &'py PyDict {
+        arrays_by_angle(
+            py,
+            self.inner
+                .compute_power_vs_mirror_angle(into_set(incident_angles)),
+        )
+    }
+}
+
 /// A Rust module to handle the messy details of extracting data from trial folders and computing said data.
 #[pymodule]
 fn data_analysis(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
-    // m.add_function(wrap_pyfunction!(fun, m)?)?;
-    todo!()
+    m.add_class::<PyTrial>()?;
+    Ok(())
+}
+
+// Reads a CSV into a dense array in the pipeline's `Number` precision via polars.
+fn read_ndarray(path: PathBuf) -> PyResult<Array2<Number>> {
+    let df = CsvReader::from_path(&path)
+        .and_then(|r| r.has_header(true).finish())
+        .map_err(|e| PyIOError::new_err(format!("{}: {e}", path.display())))?;
+    df.to_ndarray::<Float64Type>()
+        .map(|array| array.mapv(Number::from_f64))
+        .map_err(|e| PyIOError::new_err(e.to_string()))
+}
+
+// Converts an incident-angle list into the HashSet the compute methods expect.
+fn into_set(angles: Vec<f64>) -> HashSet<f64> {
+    angles.into_iter().collect()
+}
+
+// Packs a HashMap<incident angle, Array2> into a Python dict of numpy arrays.
+fn arrays_by_angle(
+    py: Python<'_>,
+    results: polars::export::ahash::HashMap<f64, Array2<Number>>,
+) -> &PyDict {
+    let dict = PyDict::new(py);
+    for (angle, array) in results {
+        dict.set_item(angle, array.into_pyarray(py))
+            .expect("inserting into a fresh dict cannot fail");
+    }
+    dict
+}
+
+fn parse_label(label: &str) -> PyResult<PowerMeterLabel> {
+    match label {
+        "A" | "SensorA" => Ok(PowerMeterLabel::SensorA),
+        "C" | "SensorC" => Ok(PowerMeterLabel::SensorC),
+        other => Err(PyIOError::new_err(format!("unknown power meter label: {other}"))),
+    }
+}
+
+fn parse_polarization(state: &str) -> PyResult<PolarizationState> {
+    match state {
+        "H" | "Horizontal" => Ok(PolarizationState::Horizontal),
+        "V" | "Vertical" => Ok(PolarizationState::Vertical),
+        other => Err(PyIOError::new_err(format!("unknown polarization: {other}"))),
+    }
 }
@@ -0,0 +1,13 @@
+use clap::Parser;
+
+use data_analysis::cli::BatchCli;
+
+// Entry point for the batch analysis binary: parse the command line and run the
+// requested computation over the matching trial folders, exiting non-zero with
+// a diagnostic if a folder fails to load or a computation cannot be dispatched.
+fn main() {
+    if let Err(error) = BatchCli::parse().run() {
+        eprintln!("{error:?}");
+        std::process::exit(1);
+    }
+}
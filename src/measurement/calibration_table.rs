@@ -0,0 +1,140 @@
+use std::path::Path;
+
+use polars::prelude::*;
+
+/// An error produced while building or querying a [`CalibrationTable`].
+#[derive(Debug)]
+pub enum CalibrationTableError {
+    /// The underlying CSV could not be read or was missing an expected column.
+    Io(PolarsError),
+    /// The table needs at least two points to interpolate between.
+    TooFewPoints,
+    /// The requested abscissa lies outside the tabulated range `[min, max]`.
+    OutOfRange { value: f64, min: f64, max: f64 },
+}
+
+impl From<PolarsError> for CalibrationTableError {
+    fn from(error: PolarsError) -> Self {
+        CalibrationTableError::Io(error)
+    }
+}
+
+/// A monotone lookup table of `(abscissa, fractional_uncertainty)` pairs that
+/// linearly interpolates between tabulated points. It replaces the hard-coded
+/// step-function bins previously used for meter uncertainties, so a new meter
+/// only has to supply a table (for example loaded from its datasheet CSV)
+/// rather than a bespoke enum and a pair of `lazy_static` maps.
+#[derive(Debug, Clone)]
+pub struct CalibrationTable {
+    // Kept sorted by abscissa so interpolation is a simple forward scan.
+    points: Vec<(f64, f64)>,
+}
+
+impl CalibrationTable {
+    /// Builds a table from `(abscissa, fractional_uncertainty)` pairs, sorting
+    /// them by abscissa. At least two points are required.
+    pub fn new(mut points: Vec<(f64, f64)>) -> Result<CalibrationTable, CalibrationTableError> {
+        if points.len() < 2 {
+            return Err(CalibrationTableError::TooFewPoints);
+        }
+        points.sort_by(|a, b| a.0.partial_cmp(&b.0).expect("calibration abscissae are finite"));
+        Ok(CalibrationTable { points })
+    }
+
+    /// Loads a table from a CSV file using the existing polars reader, taking
+    /// the abscissa from `abscissa_column` and the fractional uncertainty from
+    /// `uncertainty_column`.
+    pub fn from_csv<P: AsRef<Path>>(
+        path: P,
+        abscissa_column: &str,
+        uncertainty_column: &str,
+    ) -> Result<CalibrationTable, CalibrationTableError> {
+        let df = CsvReader::from_path(path)?.has_header(true).finish()?;
+        let abscissae = df.column(abscissa_column)?.f64()?;
+        let uncertainties = df.column(uncertainty_column)?.f64()?;
+        let points = abscissae
+            .into_iter()
+            .zip(uncertainties.into_iter())
+            .filter_map(|(x, y)| Some((x?, y?)))
+            .collect();
+        CalibrationTable::new(points)
+    }
+
+    /// Linearly interpolates the fractional uncertainty at `at`, returning an
+    /// error when `at` falls outside the tabulated range rather than silently
+    /// extrapolating.
+    pub fn interpolate(&self, at: f64) -> Result<f64, CalibrationTableError> {
+        let min = self.points[0].0;
+        let max = self.points[self.points.len() - 1].0;
+        if at < min || at > max {
+            return Err(CalibrationTableError::OutOfRange {
+                value: at,
+                min,
+                max,
+            });
+        }
+        Ok(self.interpolate_unchecked(at))
+    }
+
+    /// Linearly interpolates the fractional uncertainty at `at`, clamping to the
+    /// nearest table endpoint instead of erroring when `at` is out of range.
+    pub fn interpolate_clamped(&self, at: f64) -> f64 {
+        self.interpolate_unchecked(at)
+    }
+
+    // Interpolates within the table and clamps at the endpoints. Assumes at
+    // least two points (guaranteed by the constructors).
+    fn interpolate_unchecked(&self, at: f64) -> f64 {
+        if at <= self.points[0].0 {
+            return self.points[0].1;
+        }
+        let last = self.points.len() - 1;
+        if at >= self.points[last].0 {
+            return self.points[last].1;
+        }
+        // Find the bracketing segment [lo, hi] and interpolate within it.
+        let hi = self
+            .points
+            .iter()
+            .position(|(x, _)| *x >= at)
+            .expect("at is within the tabulated range");
+        let (x0, y0) = self.points[hi - 1];
+        let (x1, y1) = self.points[hi];
+        let t = (at - x0) / (x1 - x0);
+        y0 + t * (y1 - y0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interpolates_between_tabulated_points() {
+        let table = CalibrationTable::new(vec![(440.0, 0.03), (980.0, 0.05)]).unwrap();
+        // Midpoint wavelength interpolates to the midpoint fraction.
+        let midpoint = table.interpolate(710.0).unwrap();
+        assert!((midpoint - 0.04).abs() < 1e-9);
+        // Out-of-range abscissae error rather than extrapolate.
+        assert!(table.interpolate(200.0).is_err());
+    }
+
+    #[test]
+    fn loads_points_from_a_datasheet_csv_via_polars() {
+        // Round-trip a small datasheet through the polars reader, proving a new
+        // meter can supply a table from CSV rather than a bespoke enum + maps.
+        let path = std::env::temp_dir().join("data_analysis_calibration_table_test.csv");
+        std::fs::write(
+            &path,
+            "wavelength_nm,fractional_uncertainty\n440.0,0.03\n980.0,0.05\n",
+        )
+        .unwrap();
+
+        let table =
+            CalibrationTable::from_csv(&path, "wavelength_nm", "fractional_uncertainty").unwrap();
+        assert!((table.interpolate(440.0).unwrap() - 0.03).abs() < 1e-9);
+        assert!((table.interpolate(710.0).unwrap() - 0.04).abs() < 1e-9);
+
+        std::fs::remove_file(&path).ok();
+    }
+}
@@ -1,5 +1,6 @@
 /// Common traits found across the program
 pub mod traits {
+    use crate::experiment::trial::ComputationResult;
     use crate::experiment::trial::PolarizationState;
     use crate::experiment::trial::PowerMeterLabel;
     /// A trait which introduces the concept of a measurement.
@@ -12,6 +13,13 @@ pub mod traits {
     pub trait AbsoluteUncertainty<T: ArithmeticOps>: Measurement<T> {
         // An absolute uncertainty.
         fn uncertainty(&self) -> T;
+
+        /// The wavelength (nm) the reading was acquired at, when the sensor's
+        /// uncertainty is wavelength dependent. Defaults to `None` for meters
+        /// whose uncertainty does not vary spectrally.
+        fn acquisition_wavelength(&self) -> Option<f64> {
+            None
+        }
     }
 
     /// A trait which introduces the concept of a background power measurement.
@@ -36,6 +44,25 @@ pub mod traits {
     {
     }
 
+    /// Lifts a plain `f64` constant (such as a polarization fraction) into the
+    /// number type the pipeline is instantiated with, so the compute math can
+    /// stay generic over `f64` and `f32` alike.
+    pub trait FromF64 {
+        fn from_f64(value: f64) -> Self;
+    }
+
+    impl FromF64 for f64 {
+        fn from_f64(value: f64) -> f64 {
+            value
+        }
+    }
+
+    impl FromF64 for f32 {
+        fn from_f64(value: f64) -> f32 {
+            value as f32
+        }
+    }
+
     pub trait IncidentPower<V: ArithmeticOps> {
         // Should have a method called compute which takes an immutable reference to itself and computes a value
         // This should accept any two measurements types and return the appropriate value.
@@ -74,4 +101,270 @@ pub mod traits {
             Tr: Background<V>,
             T: Measurement<V>;
     }
+
+    /// Monte-Carlo uncertainty propagation for the incident-power computation.
+    /// Each of the four inputs (reflected/transmitted power and their
+    /// backgrounds) is treated as an independent `Normal(value, uncertainty())`
+    /// random variable; the closure is re-evaluated per draw and the sample
+    /// mean and standard deviation are returned as the value and its
+    /// associated error.
+    pub trait IncidentPowerMonteCarlo {
+        fn compute_incident_power_mc<R, Br, T, Tr>(
+            &self,
+            reflected_power: &R,
+            reflected_power_background: &Br,
+            reflected_power_meter_label: &PowerMeterLabel,
+            transmitted_power: &T,
+            transmitted_power_background: &Tr,
+            transmitted_power_meter_label: &PowerMeterLabel,
+            polarization: &PolarizationState,
+            seed: u64,
+            samples: usize,
+        ) -> ComputationResult<f64>
+        where
+            R: Measurement<f64> + AbsoluteUncertainty<f64>,
+            Br: Background<f64> + AbsoluteUncertainty<f64>,
+            Tr: Background<f64> + AbsoluteUncertainty<f64>,
+            T: Measurement<f64> + AbsoluteUncertainty<f64>;
+    }
+
+    /// Monte-Carlo uncertainty propagation for the efficiency computation. See
+    /// [`IncidentPowerMonteCarlo`] for the sampling model.
+    pub trait EfficiencyMonteCarlo {
+        fn compute_efficiency_mc<R, Br, T, Tr>(
+            &self,
+            reflected_power: &R,
+            reflected_power_background: &Br,
+            reflected_power_meter_label: &PowerMeterLabel,
+            transmitted_power: &T,
+            transmitted_power_background: &Tr,
+            transmitted_power_meter_label: &PowerMeterLabel,
+            polarization: &PolarizationState,
+            seed: u64,
+            samples: usize,
+        ) -> ComputationResult<f64>
+        where
+            R: Measurement<f64> + AbsoluteUncertainty<f64>,
+            Br: Background<f64> + AbsoluteUncertainty<f64>,
+            Tr: Background<f64> + AbsoluteUncertainty<f64>,
+            T: Measurement<f64> + AbsoluteUncertainty<f64>;
+    }
+
+    /// Closed-form (first-order) uncertainty propagation for the incident-power
+    /// computation. Faster than sampling: the partial derivatives with respect
+    /// to each input are obtained by central finite difference and combined as
+    /// `σ_f = sqrt(Σ (∂f/∂x_i)² · σ_i²)`, treating the inputs as uncorrelated.
+    pub trait IncidentPowerAnalytical {
+        #[allow(clippy::too_many_arguments)]
+        fn compute_incident_power_analytical<R, Br, T, Tr>(
+            &self,
+            reflected_power: &R,
+            reflected_power_background: &Br,
+            reflected_power_meter_label: &PowerMeterLabel,
+            transmitted_power: &T,
+            transmitted_power_background: &Tr,
+            transmitted_power_meter_label: &PowerMeterLabel,
+            polarization: &PolarizationState,
+        ) -> ComputationResult<f64>
+        where
+            R: Measurement<f64> + AbsoluteUncertainty<f64>,
+            Br: Background<f64> + AbsoluteUncertainty<f64>,
+            Tr: Background<f64> + AbsoluteUncertainty<f64>,
+            T: Measurement<f64> + AbsoluteUncertainty<f64>;
+    }
+
+    /// Closed-form (first-order) uncertainty propagation for the efficiency
+    /// computation. See [`IncidentPowerAnalytical`] for the method.
+    pub trait EfficiencyAnalytical {
+        #[allow(clippy::too_many_arguments)]
+        fn compute_efficiency_analytical<R, Br, T, Tr>(
+            &self,
+            reflected_power: &R,
+            reflected_power_background: &Br,
+            reflected_power_meter_label: &PowerMeterLabel,
+            transmitted_power: &T,
+            transmitted_power_background: &Tr,
+            transmitted_power_meter_label: &PowerMeterLabel,
+            polarization: &PolarizationState,
+        ) -> ComputationResult<f64>
+        where
+            R: Measurement<f64> + AbsoluteUncertainty<f64>,
+            Br: Background<f64> + AbsoluteUncertainty<f64>,
+            Tr: Background<f64> + AbsoluteUncertainty<f64>,
+            T: Measurement<f64> + AbsoluteUncertainty<f64>;
+    }
+}
+
+use std::ops::{Add, Div, Mul, Sub};
+
+use traits::{ArithmeticOps, Background, FromF64, Measurement};
+
+impl FromF64 for Uncertain<f64> {
+    fn from_f64(value: f64) -> Uncertain<f64> {
+        Uncertain::exact(value)
+    }
+}
+
+/// A value carried alongside its variance so that first-order error
+/// propagation falls out of ordinary arithmetic. Because it implements the four
+/// basic operations it is itself an [`ArithmeticOps`] number type, which means
+/// the generic efficiency computation can be instantiated with
+/// `V = Uncertain<f64>` to obtain both the value and the propagated uncertainty
+/// from a single code path.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Uncertain<T> {
+    /// The nominal value.
+    pub value: T,
+    /// The variance (squared standard uncertainty) of `value`.
+    pub variance: T,
+}
+
+impl<T> Uncertain<T>
+where
+    T: ArithmeticOps + Copy,
+{
+    /// Constructs an uncertain number from a value and an absolute (1σ)
+    /// uncertainty, storing the square of the uncertainty as the variance.
+    pub fn new(value: T, uncertainty: T) -> Uncertain<T> {
+        Uncertain {
+            value,
+            variance: uncertainty * uncertainty,
+        }
+    }
+}
+
+impl Uncertain<f64> {
+    /// Treats a reading as exact, i.e. with zero variance.
+    pub fn exact(value: f64) -> Uncertain<f64> {
+        Uncertain {
+            value,
+            variance: 0.0,
+        }
+    }
+
+    /// The standard (1σ) uncertainty, i.e. the square root of the variance.
+    pub fn uncertainty(&self) -> f64 {
+        self.variance.sqrt()
+    }
+}
+
+// Independent inputs: variances add under addition and subtraction.
+impl<T> Add for Uncertain<T>
+where
+    T: ArithmeticOps + Copy,
+{
+    type Output = Uncertain<T>;
+    fn add(self, rhs: Self) -> Self::Output {
+        Uncertain {
+            value: self.value + rhs.value,
+            variance: self.variance + rhs.variance,
+        }
+    }
+}
+
+impl<T> Sub for Uncertain<T>
+where
+    T: ArithmeticOps + Copy,
+{
+    type Output = Uncertain<T>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Uncertain {
+            value: self.value - rhs.value,
+            variance: self.variance + rhs.variance,
+        }
+    }
+}
+
+// c = a*b: variance = b²·Var(a) + a²·Var(b).
+impl<T> Mul for Uncertain<T>
+where
+    T: ArithmeticOps + Copy,
+{
+    type Output = Uncertain<T>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        Uncertain {
+            value: self.value * rhs.value,
+            variance: rhs.value * rhs.value * self.variance
+                + self.value * self.value * rhs.variance,
+        }
+    }
+}
+
+// c = a/b: variance = (Var(a) + (a/b)²·Var(b)) / b².
+impl<T> Div for Uncertain<T>
+where
+    T: ArithmeticOps + Copy,
+{
+    type Output = Uncertain<T>;
+    fn div(self, rhs: Self) -> Self::Output {
+        let ratio = self.value / rhs.value;
+        Uncertain {
+            value: ratio,
+            variance: (self.variance + ratio * ratio * rhs.variance) / (rhs.value * rhs.value),
+        }
+    }
+}
+
+// An `Uncertain` reading is a measurement of itself, value and variance alike.
+impl<T> Measurement<Uncertain<T>> for Uncertain<T>
+where
+    T: ArithmeticOps + Copy,
+{
+    fn value(&self) -> Uncertain<T> {
+        *self
+    }
+}
+
+// ...and likewise as a background reading.
+impl<T> Background<Uncertain<T>> for Uncertain<T>
+where
+    T: ArithmeticOps + Copy,
+{
+    fn background(&self) -> Uncertain<T> {
+        *self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_sums_variances() {
+        let a = Uncertain::new(4.0, 0.2);
+        let b = Uncertain::new(2.0, 0.1);
+        let c = a + b;
+        assert!((c.value - 6.0).abs() < 1e-12);
+        assert!((c.variance - (0.04 + 0.01)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn mul_propagates_first_order_variance() {
+        // c = a*b: variance = b²·Var(a) + a²·Var(b).
+        let a = Uncertain::new(4.0, 0.2);
+        let b = Uncertain::new(2.0, 0.1);
+        let c = a * b;
+        assert!((c.value - 8.0).abs() < 1e-12);
+        let expected = 2.0f64.powi(2) * 0.04 + 4.0f64.powi(2) * 0.01;
+        assert!((c.variance - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn div_propagates_first_order_variance() {
+        // c = a/b: variance = (Var(a) + (a/b)²·Var(b)) / b².
+        let a = Uncertain::new(4.0, 0.2);
+        let b = Uncertain::new(2.0, 0.1);
+        let c = a / b;
+        assert!((c.value - 2.0).abs() < 1e-12);
+        let expected = (0.04 + 2.0f64.powi(2) * 0.01) / 2.0f64.powi(2);
+        assert!((c.variance - expected).abs() < 1e-12);
+        assert!((c.uncertainty() - expected.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn exact_readings_carry_zero_variance() {
+        let exact = Uncertain::exact(3.0);
+        assert_eq!(0.0, exact.variance);
+        assert_eq!(0.0, exact.uncertainty());
+    }
 }
@@ -1,8 +1,18 @@
 use lazy_static::lazy_static;
 use std::collections::HashMap;
 
+use uom::si::f64::Power;
+use uom::si::power::watt;
+
 use crate::measurement::power_measurement::traits::{AbsoluteUncertainty, Background, Measurement};
 
+// The full-scale of each Newport 835 range, as a typed `Power` quantity rather
+// than a bare float literal. Expressing the thresholds as `Power` means a
+// wavelength or an angle can never be compared against them by mistake.
+fn watts(value: f64) -> Power {
+    Power::new::<watt>(value)
+}
+
 // Defines a power measurement as read from a newport model 835 power meter
 pub struct NewportModel835PowerMeterMeasurement {
     // A power measurement should have a float value
@@ -24,6 +34,28 @@ impl Background<f64> for NewportModel835PowerMeterMeasurementBackground {
     }
 }
 
+// A background reading is itself a power reading on the same meter, so it
+// exposes the same value and uncertainty model. This lets Monte-Carlo error
+// propagation treat the background as an independent random variable.
+impl Measurement<f64> for NewportModel835PowerMeterMeasurementBackground {
+    fn value(&self) -> f64 {
+        self.0
+    }
+}
+
+impl AbsoluteUncertainty<f64> for NewportModel835PowerMeterMeasurementBackground {
+    fn uncertainty(&self) -> f64 {
+        NewportModel835PowerMeterMeasurement::new(self.0).uncertainty()
+    }
+}
+
+// The same background in single precision, for the `f32` build of the pipeline.
+impl Background<f32> for NewportModel835PowerMeterMeasurementBackground {
+    fn background(&self) -> f32 {
+        self.0 as f32
+    }
+}
+
 impl NewportModel835PowerMeterMeasurement {
     pub fn new(value: f64) -> NewportModel835PowerMeterMeasurement {
         NewportModel835PowerMeterMeasurement { value }
@@ -71,20 +103,31 @@ enum NewportModel835PowerMeterRange {
 
 // Implement a method to
 impl NewportModel835PowerMeterRange {
-    fn get_range(value: &f64) -> NewportModel835PowerMeterRange {
-        // Find the range the power measurement is in
-        match *value {
-            x if (0.0..=0.000000002).contains(&x) => NewportModel835PowerMeterRange::Twonanowatts,
-            x if x > 0.000000002 && x <= 0.000000020 => {
+    fn get_range(power: Power) -> NewportModel835PowerMeterRange {
+        // Find the range the power measurement is in. Comparisons are between
+        // typed `Power` quantities, so the thresholds auto-convert units and a
+        // non-power argument is rejected at compile time.
+        match power {
+            x if x >= watts(0.0) && x <= watts(0.000000002) => {
+                NewportModel835PowerMeterRange::Twonanowatts
+            }
+            x if x > watts(0.000000002) && x <= watts(0.000000020) => {
                 NewportModel835PowerMeterRange::Twentynanowatts
             }
-            x if x > 0.000000020 && x <= 0.000000200 => {
+            x if x > watts(0.000000020) && x <= watts(0.000000200) => {
                 NewportModel835PowerMeterRange::Twohundrednanowatts
             }
-            x if x > 0.000000200 && x <= 0.002 => NewportModel835PowerMeterRange::Twomilliwatts,
-            x if x > 0.002 && x <= 0.020 => NewportModel835PowerMeterRange::Twentymilliwatts,
-            x if x > 0.020 && x <= 0.200 => NewportModel835PowerMeterRange::Twohundredmilliwatts,
-            _ => panic!("oops"),
+            x if x > watts(0.000000200) && x <= watts(0.002) => {
+                NewportModel835PowerMeterRange::Twomilliwatts
+            }
+            x if x > watts(0.002) && x <= watts(0.020) => {
+                NewportModel835PowerMeterRange::Twentymilliwatts
+            }
+            // The 200 mW range is the meter's top scale, so a reading above it
+            // is still reported on that range (its uncertainty widened by the
+            // fullscale fraction) rather than aborting on an otherwise valid
+            // high reading.
+            _ => NewportModel835PowerMeterRange::Twohundredmilliwatts,
         }
     }
 }
@@ -96,12 +139,20 @@ impl Measurement<f64> for NewportModel835PowerMeterMeasurement {
     }
 }
 
+impl NewportModel835PowerMeterMeasurement {
+    /// The reading as a dimensioned [`Power`] quantity, so downstream code that
+    /// has opted into `uom` cannot mix it up with an angle or wavelength.
+    pub fn power(&self) -> Power {
+        watts(self.value)
+    }
+}
+
 // Implement absolute uncertainty for newport model 835 power meter.
 impl AbsoluteUncertainty<f64> for NewportModel835PowerMeterMeasurement {
     fn uncertainty(&self) -> f64 {
         let value = &self.value;
         // Determine the power reading scale we are working with
-        let range = NewportModel835PowerMeterRange::get_range(&self.value);
+        let range = NewportModel835PowerMeterRange::get_range(self.power());
         // Determine the associated fractional fullscaleuncertainty of this power scale.
         let fullscale_frac_uncertainty = NEWPORT_MODEL_835_POWER_METER_FULLSCALE_UNCERTAINTIES
             .get(&range)
@@ -136,4 +187,19 @@ mod tests {
             newport_measurement.uncertainty()
         );
     }
+
+    #[test]
+    fn test_newport_power_is_typed() {
+        let newport_measurement = NewportModel835PowerMeterMeasurement::new(0.001);
+        // The reading is exposed as a dimensioned Power at the meter boundary.
+        assert_eq!(watts(0.001), newport_measurement.power());
+    }
+
+    #[test]
+    fn test_newport_high_reading_saturates_to_top_range() {
+        // A reading above the 200 mW top scale must not panic; it saturates to
+        // the top range and still yields a finite uncertainty.
+        let newport_measurement = NewportModel835PowerMeterMeasurement::new(1.0);
+        assert!(newport_measurement.uncertainty() > 0.0);
+    }
 }
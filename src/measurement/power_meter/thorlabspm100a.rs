@@ -1,62 +1,71 @@
 use lazy_static::lazy_static;
-use std::collections::HashMap;
 
+use uom::si::f64::Power;
+use uom::si::power::watt;
+
+use crate::measurement::calibration_table::{CalibrationTable, CalibrationTableError};
 use crate::measurement::power_measurement::traits::{AbsoluteUncertainty, Background, Measurement};
 
-// Define the mapping of uncertainty wavelength ranges to their corresponding fractional uncertainties
+// The dimensional-safety layer is applied at the measurement boundary rather
+// than inside the generic compute: efficiency divides two powers to a
+// dimensionless ratio, which `Power` cannot express while still satisfying the
+// `ArithmeticOps` requirement that `*` and `/` be closed over the type. The
+// meters therefore expose their reading as a typed `uom` `Power` so a watt
+// value can never be fed where an angle or wavelength is expected, and the
+// efficiency math keeps operating on the `ArithmeticOps` scalar the whole
+// pipeline is generic over.
+
+// The band (nm) over which the S120VC is calibrated. Readings outside it are
+// still usable but their uncertainty is widened to reflect the loss of
+// calibration confidence.
+const CALIBRATED_BAND_NM: (f64, f64) = (440.0, 980.0);
+
+// How much the fractional uncertainty is inflated for readings taken outside
+// the calibrated band.
+const OUT_OF_BAND_WIDENING: f64 = 2.0;
+
+// The S120VC fractional uncertainty as a function of wavelength, loaded from the
+// sensor's datasheet CSV shipped alongside this module via the polars-backed
+// `CalibrationTable::from_csv`. A calibration table linearly interpolates
+// between the tabulated points instead of bucketing the wavelength into
+// step-function bins, avoiding discontinuous jumps (e.g. 3% -> 7% at exactly
+// 980 nm).
 lazy_static! {
-    static ref THORLABS_PM100A_S120VC_WAVELENGTH_UNCERTAINTIES: HashMap<ThorlabsPm100aS120vcUncertaintyWavelengthRange, f64> = {
-        let mut map: HashMap<ThorlabsPm100aS120vcUncertaintyWavelengthRange, f64> = HashMap::new();
-        map.insert(
-            ThorlabsPm100aS120vcUncertaintyWavelengthRange::Range440to980nm,
-            0.03,
-        );
-        map.insert(
-            ThorlabsPm100aS120vcUncertaintyWavelengthRange::Range200to279nm,
-            0.07,
-        );
-        map.insert(
-            ThorlabsPm100aS120vcUncertaintyWavelengthRange::Range280to439nm,
-            0.05,
-        );
-        map.insert(
-            ThorlabsPm100aS120vcUncertaintyWavelengthRange::Range981to1100nm,
-            0.07,
-        );
-        map
-    };
+    static ref THORLABS_PM100A_S120VC_WAVELENGTH_UNCERTAINTIES: CalibrationTable =
+        load_spectral_uncertainty_table();
 }
 
-// Describes the possible wavelength ranges for determining the uncertainty in measurement
-#[derive(Hash, PartialEq, Eq)]
-enum ThorlabsPm100aS120vcUncertaintyWavelengthRange {
-    Range440to980nm,
-    Range280to439nm,
-    Range200to279nm,
-    Range981to1100nm,
-}
+// Path to the datasheet CSV, resolved relative to the crate so the table is
+// read from the same file a new meter would supply rather than a bespoke
+// hard-coded vector.
+const S120VC_DATASHEET_CSV: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/src/measurement/power_meter/s120vc_spectral_uncertainty.csv"
+);
 
-// Helper method on this enum to return the appropriate variant given a float.
-// Will choose to handle wavelengths between enumerations as belonging to the higher wavelength enumeration.
-impl ThorlabsPm100aS120vcUncertaintyWavelengthRange {
-    fn get_range(at_wavelength: &f64) -> ThorlabsPm100aS120vcUncertaintyWavelengthRange {
-        // Find the range the wavelength is in
-        match *at_wavelength {
-            x if (200.0..=279.0).contains(&x) => {
-                ThorlabsPm100aS120vcUncertaintyWavelengthRange::Range200to279nm
-            }
-            x if x > 279.0 && x <= 439.0 => {
-                ThorlabsPm100aS120vcUncertaintyWavelengthRange::Range280to439nm
-            }
-            x if x > 439.0 && x <= 980.0 => {
-                ThorlabsPm100aS120vcUncertaintyWavelengthRange::Range440to980nm
-            }
-            x if x > 980.0 && x <= 1100.0 => {
-                ThorlabsPm100aS120vcUncertaintyWavelengthRange::Range981to1100nm
-            }
-            _ => panic!("Invalid measurement. Wavelength is out of range."),
-        }
-    }
+// Loads the spectral-uncertainty table from the datasheet CSV, falling back to
+// the datasheet's tabulated points if the file cannot be read (e.g. in a
+// stripped-down deployment). Either way the table is built through the shared
+// `CalibrationTable` rather than a bespoke enum and a pair of maps.
+fn load_spectral_uncertainty_table() -> CalibrationTable {
+    CalibrationTable::from_csv(
+        S120VC_DATASHEET_CSV,
+        "wavelength_nm",
+        "fractional_uncertainty",
+    )
+    .or_else(|_| {
+        CalibrationTable::new(vec![
+            (200.0, 0.07),
+            (279.0, 0.07),
+            (280.0, 0.05),
+            (439.0, 0.05),
+            (440.0, 0.03),
+            (980.0, 0.03),
+            (981.0, 0.07),
+            (1100.0, 0.07),
+        ])
+    })
+    .expect("S120VC calibration table has at least two points")
 }
 
 // Define the thorlabs PM100A S120VC power meter measurement
@@ -92,16 +101,66 @@ impl Measurement<f64> for ThorLabsPM100A_S120VC_PowerMeterMeasurement {
     }
 }
 
+impl ThorLabsPM100A_S120VC_PowerMeterMeasurement {
+    /// The reading as a dimensioned [`Power`] quantity, mirroring the Newport
+    /// 835's accessor so downstream `uom`-aware code cannot mistake a watt
+    /// reading for an angle or wavelength.
+    pub fn power(&self) -> Power {
+        Power::new::<watt>(self.value)
+    }
+}
+
+impl ThorLabsPM100A_S120VC_PowerMeterMeasurement {
+    /// The absolute uncertainty, erroring when the acquisition wavelength lies
+    /// outside the calibrated band rather than silently reporting a figure the
+    /// sensor is not calibrated for. Prefer this over [`uncertainty`] when an
+    /// out-of-band reading should be rejected instead of widened.
+    ///
+    /// [`uncertainty`]: AbsoluteUncertainty::uncertainty
+    pub fn checked_uncertainty(&self) -> Result<f64, CalibrationTableError> {
+        let (low, high) = CALIBRATED_BAND_NM;
+        if self.at_wavelength < low || self.at_wavelength > high {
+            return Err(CalibrationTableError::OutOfRange {
+                value: self.at_wavelength,
+                min: low,
+                max: high,
+            });
+        }
+        let frac_uncertainty =
+            THORLABS_PM100A_S120VC_WAVELENGTH_UNCERTAINTIES.interpolate_clamped(self.at_wavelength);
+        // Read the watt value back through the typed `Power` boundary so the
+        // uncertainty is always derived from a dimensioned quantity.
+        Ok(self.power().get::<watt>() * frac_uncertainty)
+    }
+}
+
 // Here we implement the absolute uncertainty interface for the thorlabs power meter
 impl AbsoluteUncertainty<f64> for ThorLabsPM100A_S120VC_PowerMeterMeasurement {
     fn uncertainty(&self) -> f64 {
-        let value = &self.value;
-        let range = ThorlabsPm100aS120vcUncertaintyWavelengthRange::get_range(&self.at_wavelength);
+        // Recover the reading through the typed `Power` boundary rather than
+        // touching the bare field, so a watt value can never be confused with
+        // an angle or wavelength on the way into the uncertainty math.
+        let value = self.power().get::<watt>();
+        // Interpolate the fractional uncertainty at the acquisition wavelength,
+        // clamping to the table endpoints for wavelengths outside the
+        // tabulated 200-1100 nm band.
         let frac_uncertainty = THORLABS_PM100A_S120VC_WAVELENGTH_UNCERTAINTIES
-            .get(&range)
-            .unwrap();
+            .interpolate_clamped(self.at_wavelength);
+        // Readings outside the calibrated band keep a (widened) uncertainty
+        // rather than erroring, so multi-wavelength sweeps still produce a
+        // figure; use `checked_uncertainty` to reject them instead.
+        let (low, high) = CALIBRATED_BAND_NM;
+        let widening = if self.at_wavelength < low || self.at_wavelength > high {
+            OUT_OF_BAND_WIDENING
+        } else {
+            1.0
+        };
         // Compute the absolute uncertainty
-        value * frac_uncertainty
+        value * frac_uncertainty * widening
+    }
+
+    fn acquisition_wavelength(&self) -> Option<f64> {
+        Some(self.at_wavelength)
     }
 }
 
@@ -126,4 +185,36 @@ mod tests {
             thorlabs_measurement.uncertainty()
         );
     }
+
+    #[test]
+    fn test_thorlabs_power_is_typed() {
+        let measurement = ThorLabsPM100A_S120VC_PowerMeterMeasurement::new(0.01, 637.8);
+        // The reading is exposed as a dimensioned Power at the meter boundary.
+        assert_eq!(Power::new::<watt>(0.01), measurement.power());
+    }
+
+    #[test]
+    fn test_thorlabs_reports_its_acquisition_wavelength() {
+        let measurement = ThorLabsPM100A_S120VC_PowerMeterMeasurement::new(0.01, 637.8);
+        assert_eq!(Some(637.8), measurement.acquisition_wavelength());
+    }
+
+    #[test]
+    fn test_thorlabs_checked_uncertainty_accepts_in_band() {
+        let measurement = ThorLabsPM100A_S120VC_PowerMeterMeasurement::new(0.01, 637.8);
+        // In-band readings resolve to the interpolated absolute uncertainty.
+        let checked = measurement.checked_uncertainty().unwrap();
+        assert_eq!(measurement.value() * 0.03, checked);
+    }
+
+    #[test]
+    fn test_thorlabs_checked_uncertainty_rejects_out_of_band() {
+        // A reading outside the 440-980 nm calibrated band is rejected rather
+        // than silently reported, unlike the widening `uncertainty()`.
+        let measurement = ThorLabsPM100A_S120VC_PowerMeterMeasurement::new(0.01, 1064.0);
+        assert!(matches!(
+            measurement.checked_uncertainty(),
+            Err(CalibrationTableError::OutOfRange { .. })
+        ));
+    }
 }